@@ -0,0 +1,48 @@
+//! Compares the run time of the MDSECheck method with the one of the subspace trail
+//! algorithms of Grassi et al. for random Cauchy matrices of several orders. For each
+//! order, the mean time per matrix is printed for the MDSECheck method and for the trail
+//! algorithms with a single S-box and with the full substitution layer, together with
+//! the number of the matrices accepted by the MDSECheck method and the number of the
+//! accepted matrices, for which the trail algorithms give the same security level.
+
+use ark_bn254::Fr;
+use mdsecheck::{random_cauchy, security_level, trail};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+use std::time::{Duration, Instant};
+
+// The number of the matrices of each order
+const MATRICES: u32 = 10;
+
+// The bound of the security level
+const BOUND: u32 = 8;
+
+fn main() {
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    println!("order  mdsecheck     trail (s = 1)  trail (s = n)  accepted  agreeing");
+    for n in [3, 4, 6, 8, 10] {
+        let (mut u, mut v, mut w) = (Duration::ZERO, Duration::ZERO, Duration::ZERO);
+        let (mut a, mut b) = (0, 0);
+        for _ in 0..MATRICES {
+            let m = random_cauchy::<Fr>(n, &mut r).unwrap();
+            let t = Instant::now();
+            let l = security_level(&m, BOUND);
+            u += t.elapsed();
+            let t = Instant::now();
+            let p = trail::security_level(&m, 1, BOUND);
+            v += t.elapsed();
+            let t = Instant::now();
+            let q = trail::security_level(&m, n as usize, BOUND);
+            w += t.elapsed();
+            if l.is_some() {
+                a += 1;
+                b += u32::from((p == l) && (q == l));
+            }
+        }
+        println!(
+            "{n:5}  {:12?}  {:13?}  {:13?}  {a:8}  {b:8}",
+            u / MATRICES,
+            v / MATRICES,
+            w / MATRICES
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Provides the tools for estimating the number of the random Cauchy matrices, which should
+//! be generated to obtain a matrix with the specified unconditional P-SPN security level,
+//! and the time required for this. The estimates treat the matrices as uniformly random
+//! ones, so they are approximate, and they can be compared with the outcomes of the
+//! functions in the module "stats".
+
+use std::time::{Duration, Instant};
+
+use ark_ff::PrimeField;
+use rand::Rng;
+
+use crate::{num, random_cauchy, security_level};
+
+/// Estimates the average time of generating a random Cauchy matrix of the order specified
+/// by the first argument and checking it by means of the function "crate::security_level"
+/// with the bound specified by the second argument. The average is computed over the number
+/// of attempts specified by the third argument, which use the source of randomness specified
+/// by the fourth argument. If the third argument is 0 or the arguments are not valid for the
+/// function "probability", then None is returned.
+pub fn calibration<F: PrimeField>(
+    n: u32,
+    l: u32,
+    k: u32,
+    r: &mut (impl Rng + ?Sized),
+) -> Option<Duration> {
+    if k == 0 {
+        return None;
+    }
+    probability::<F>(n, l)?;
+    let t = Instant::now();
+    for _ in 0..k {
+        security_level(&random_cauchy::<F>(n, r)?, l);
+    }
+    Some(t.elapsed() / k)
+}
+
+/// Computes the expected number of the random Cauchy matrices, which should be generated
+/// to obtain a matrix accepted by the function "crate::security_level", by means of the
+/// function "probability" for the same arguments. If the estimated probability is 0, then
+/// the infinity is returned, and if the arguments are not valid for "probability", then
+/// None is returned.
+pub fn expected_attempts<F: PrimeField>(n: u32, l: u32) -> Option<f64> {
+    Some(1.0 / probability::<F>(n, l)?)
+}
+
+/// Estimates the probability that the random square matrix of the order specified by the
+/// first argument has the unconditional P-SPN security level no less than the second
+/// argument. A random matrix has an irreducible characteristic polynomial with the
+/// probability I(n) / p^n * (1 - p^(1 - n)) * ... * (1 - p^(-1)), where p is the field
+/// size, n is the matrix order, and the number I(n) of the monic irreducible polynomials
+/// of degree n is computed by means of the Moebius formula. In this case, a random root
+/// a of the polynomial is an element of degree n of the extension field, and a^i belongs
+/// to the maximal subfield of the degree n / q, where q is a prime divisor of n, if and
+/// only if the order of a divides i * (p^(n / q) - 1). The numbers of such elements for
+/// all i between 2 and the second argument are computed exactly by means of the orders of
+/// the elements in the cyclic groups, and their sum for all maximal subfields is used as
+/// the upper bound of the number of the elements, whose powers leave the extension field
+/// prematurely. If the first argument is less than 2, the second argument is 0 or the
+/// field does not have enough elements for the matrix order, then None is returned.
+pub fn probability<F: PrimeField>(n: u32, l: u32) -> Option<f64> {
+    if (n < 2) || (l == 0) || (F::MODULUS < F::BigInt::from(n as u64 * 2)) {
+        return None;
+    }
+    let m = F::MODULUS;
+    let p = m
+        .as_ref()
+        .iter()
+        .rev()
+        .fold(0f64, |r, c| r * 2f64.powi(64) + *c as f64);
+    // Computing p^(-k) without computing large powers, which may overflow
+    let e = |k: u32| p.powi(-(k as i32));
+    // Computing n * I(n) / p^n, i.e. the fraction of the elements of degree n.
+    // The divisors are positive, so the Moebius function values always exist
+    let c = num::divisors(n as u64)
+        .into_iter()
+        .map(|d| num::moebius(d).unwrap() as f64 * e(n - n / d as u32))
+        .sum::<f64>();
+    let s = c / n as f64 * (1..n).map(|k| 1.0 - e(n - k)).product::<f64>();
+    let mut f = 0.0;
+    for q in num::prime_divisors(n) {
+        let t = n / q;
+        // Collecting the divisors of the orders of the images of the elements satisfying
+        // the aforementioned condition under the map x -> x^(p^t - 1), whose image is the
+        // cyclic group of the order s = 1 + p^t + ... + p^(t * (q - 1))
+        let mut d = Vec::<u64>::new();
+        for i in 2..=l {
+            let h = modular_power(num::residue(m.as_ref(), i as u64)?, t, i as u64);
+            let z = (0..q).fold((0, 1), |(z, g), _| ((z + g) % i as u64, g * h % i as u64));
+            for v in num::divisors(num::gcd(i as u64, z.0)) {
+                if !d.contains(&v) {
+                    d.push(v);
+                }
+            }
+        }
+        // Each element of the image of the order v has p^t - 1 preimages, and
+        // the elements, whose images have the order 1, belong to the subfield
+        let k = d
+            .iter()
+            .filter(|v| **v > 1)
+            .map(|v| num::totient(*v))
+            .sum::<u64>();
+        f += k as f64 * (e(n - t) - e(n)) / c;
+    }
+    Some(s * (1.0 - f).max(0.0))
+}
+
+/// Estimates the time of generating random Cauchy matrices until a matrix accepted by the
+/// function "crate::security_level" is obtained, as the product of the outputs of the
+/// functions "expected_attempts" and "calibration" for the same arguments. If the arguments
+/// are not valid for "calibration" or the estimate cannot be represented, then None is
+/// returned.
+pub fn run_time<F: PrimeField>(
+    n: u32,
+    l: u32,
+    k: u32,
+    r: &mut (impl Rng + ?Sized),
+) -> Option<Duration> {
+    let t = calibration::<F>(n, l, k, r)?;
+    Duration::try_from_secs_f64(t.as_secs_f64() * expected_attempts::<F>(n, l)?).ok()
+}
+
+// Computes the first argument raised to the power of the second modulo the third
+fn modular_power(a: u64, mut e: u32, m: u64) -> u64 {
+    let (mut a, mut r) = (a as u128 % m as u128, 1 % m as u128);
+    while e != 0 {
+        if e & 1 == 1 {
+            r = r * a % m as u128;
+        }
+        (a, e) = (a * a % m as u128, e >> 1);
+    }
+    r as u64
+}
@@ -0,0 +1,130 @@
+//! Provides auxiliary tools for working with extension fields.
+
+use ark_ff::{PrimeField, Zero};
+use ark_poly::{polynomial::univariate::DensePolynomial, Polynomial};
+
+use crate::poly;
+
+/// Represents an element of the quotient ring F\[x\]/(m) as the polynomial of degree less
+/// than the degree of the modulus m, to which the element is bound. The quotient ring is
+/// the extension field of the degree of m, provided that m is irreducible, and in this
+/// case the trace and the norm of the element are the trace and the norm of the field
+/// extension. Otherwise they are the trace and the determinant of the multiplication map.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtensionElement<'a, F: PrimeField> {
+    v: DensePolynomial<F>,
+    m: &'a DensePolynomial<F>,
+}
+
+impl<'a, F: PrimeField> ExtensionElement<'a, F> {
+    /// Creates the element represented by the first argument reduced modulo the second.
+    /// If the degree of the modulus is 0, then None is returned.
+    pub fn new(v: &DensePolynomial<F>, m: &'a DensePolynomial<F>) -> Option<Self> {
+        if m.degree() == 0 {
+            // The modulus is a constant, so the quotient ring is trivial
+            return None;
+        }
+        Some(Self {
+            v: poly::reduced_modulo(v, m)?,
+            m,
+        })
+    }
+
+    /// Returns the modulus, to which the element is bound.
+    pub fn modulus(&self) -> &'a DensePolynomial<F> {
+        self.m
+    }
+
+    /// Returns the reduced polynomial representing the element.
+    pub fn value(&self) -> &DensePolynomial<F> {
+        &self.v
+    }
+
+    /// Computes the sum of the element and the argument. If they
+    /// are bound to different moduli, then None is returned.
+    pub fn sum(&self, o: &Self) -> Option<Self> {
+        self.compatible(o)?;
+        Some(Self {
+            v: &self.v + &o.v,
+            m: self.m,
+        })
+    }
+
+    /// Computes the difference of the element and the argument. If
+    /// they are bound to different moduli, then None is returned.
+    pub fn difference(&self, o: &Self) -> Option<Self> {
+        self.compatible(o)?;
+        Some(Self {
+            v: &self.v - &o.v,
+            m: self.m,
+        })
+    }
+
+    /// Computes the product of the element and the argument. If they
+    /// are bound to different moduli, then None is returned.
+    pub fn product(&self, o: &Self) -> Option<Self> {
+        self.compatible(o)?;
+        Self::new(&poly::product(&self.v, &o.v), self.m)
+    }
+
+    /// Computes the multiplicative inverse of the element by means of the function
+    /// "poly::extended_gcd". If the element is not invertible, which is possible only
+    /// for 0, unless the modulus is reducible, then None is returned.
+    pub fn inverse(&self) -> Option<Self> {
+        // The cofactor of the element is its inverse,
+        // if the greatest common divisor is 1
+        let (g, s, _) = poly::extended_gcd(&self.v, self.m);
+        if g.is_zero() || (g.degree() > 0) {
+            // The element and the modulus have a nontrivial common divisor
+            return None;
+        }
+        Self::new(&s, self.m)
+    }
+
+    /// Computes the element raised to the power of the argument by means of the function
+    /// "poly::power_modulo". The argument is an unsigned integer represented by its 64-bit
+    /// chunks stored in the little-endian order.
+    pub fn power(&self, e: &[u64]) -> Self {
+        Self {
+            // The modulus is not 0, so the power always exists
+            v: poly::power_modulo(&self.v, e, self.m).unwrap(),
+            m: self.m,
+        }
+    }
+
+    /// Computes the image of the element under the k-th power of the Frobenius
+    /// endomorphism, i.e. the element raised to the power of p^k, where k is
+    /// the argument and p is the field characteristic.
+    pub fn frobenius(&self, k: u32) -> Self {
+        (0..k).fold(self.clone(), |e, _| e.power(F::characteristic()))
+    }
+
+    /// Computes the trace of the element by means of the power sums of the roots of the
+    /// modulus, since the trace is the sum of the values of the element polynomial at them.
+    pub fn trace(&self) -> F {
+        // The modulus is not a constant, so the power sums always exist
+        let s = poly::power_sums(self.m, self.m.degree()).unwrap();
+        self.v
+            .coeffs
+            .iter()
+            .zip(s.iter())
+            .map(|(x, y)| *x * y)
+            .sum()
+    }
+
+    /// Computes the norm of the element by means of the resultant of the modulus
+    /// and the element polynomial, since it equals the product of the values of the
+    /// element polynomial at the roots of the modulus multiplied by the leading
+    /// coefficient of the modulus raised to the power of the element degree.
+    pub fn norm(&self) -> F {
+        let c = self.m.coeffs[self.m.degree()].pow([self.v.degree() as u64]);
+        // The leading coefficient of the modulus is not 0
+        poly::resultant(self.m, &self.v) / c
+    }
+
+    fn compatible(&self, o: &Self) -> Option<()> {
+        // The elements are bound to the same modulus, if the references
+        // are equal, so the comparison of the polynomials is usually avoided
+        (std::ptr::eq(self.m, o.m) || (self.m == o.m)).then_some(())
+    }
+}
@@ -0,0 +1,361 @@
+//! Provides the const-generic counterparts of some functions of the crate for the matrices
+//! of the fixed order N, which are stored as arrays, so that the searches over the small
+//! widths and the embedded uses do not allocate on the heap. The elements of the quotient
+//! ring modulo the monic polynomial of degree N are stored as the arrays of their N
+//! coefficients in the ascending order, as is the monic polynomial itself, whose leading
+//! coefficient is implied. The results are exactly the same as the ones of the dynamic
+//! counterparts.
+
+use ark_ff::{Field, PrimeField};
+use rand::Rng;
+
+use crate::{mat, num};
+
+/// Computes the same value as the function "mat::product_vector" for the matrix and column
+/// vector of the fixed sizes, which are always compatible. Unlike the dynamic counterpart,
+/// the empty array is returned for the empty arguments.
+pub fn product_vector<F: Field, const M: usize, const N: usize>(
+    a: &[[F; M]; N],
+    b: &[F; M],
+) -> [F; N] {
+    let mut v = [F::ZERO; N];
+    for (e, r) in v.iter_mut().zip(a.iter()) {
+        *e = mat::dot_product(r, b);
+    }
+    v
+}
+
+/// Creates the same matrix as the function "crate::random_cauchy" for the order N and the
+/// source of randomness specified by the argument, i.e. the points are drawn in the same
+/// order, and the differences are inverted by means of the Montgomery trick as in the
+/// function "crate::batch_inverse". If N is 0 or the field does not have enough elements
+/// for the matrix order, then None is returned.
+pub fn random_cauchy<F: PrimeField, const N: usize>(
+    r: &mut (impl Rng + ?Sized),
+) -> Option<[[F; N]; N]> {
+    if (N == 0) || (F::MODULUS < F::BigInt::from(N as u64 * 2)) {
+        // The order is 0 or the field does not have
+        // enough elements for the specified matrix order
+        return None;
+    }
+    // The first N distinct points are the ones of x, and the other ones are the ones of y
+    let (mut x, mut y) = ([F::ZERO; N], [F::ZERO; N]);
+    for k in 0..2 * N {
+        let e = loop {
+            let e = F::rand(r);
+            if !x[..k.min(N)].contains(&e) && !y[..k.saturating_sub(N)].contains(&e) {
+                break e;
+            }
+        };
+        if k < N {
+            x[k] = e;
+        } else {
+            y[k - N] = e;
+        }
+    }
+    // Storing the products of the preceding differences x_j - y_i in the row-major order
+    let mut m = [[F::ZERO; N]; N];
+    let mut c = F::ONE;
+    for i in 0..N {
+        for j in 0..N {
+            m[i][j] = c;
+            c *= x[j] - y[i];
+        }
+    }
+    // Since all points are distinct, the product of the differences is invertible
+    let mut c = c.inverse().unwrap();
+    for i in (0..N).rev() {
+        for j in (0..N).rev() {
+            m[i][j] *= c;
+            c *= x[j] - y[i];
+        }
+    }
+    Some(m)
+}
+
+/// Computes the same value as the function "crate::security_level" for the matrix of the
+/// fixed order and the second argument. If the crate feature "audit" is enabled, then the
+/// value is compared with the output of the function "reference::security_level", and a
+/// panic occurs on disagreement. If N is less than 2, the second argument is 0 or the matrix
+/// is not unconditionally P-SPN secure, then None is returned.
+pub fn security_level<F: PrimeField, const N: usize>(a: &[[F; N]; N], l: u32) -> Option<u32> {
+    let s = checked_level(a, l);
+    #[cfg(feature = "audit")]
+    assert_eq!(
+        s,
+        crate::reference::security_level(a, l),
+        "The MDSECheck method disagrees with the reference checker"
+    );
+    s
+}
+
+/// Computes the same value as the function "mat::system_solution" for the matrix and column
+/// vector of the fixed sizes by means of the Gaussian elimination method. If the matrix is
+/// singular or N is 0, then None is returned.
+pub fn system_solution<F: Field, const N: usize>(a: &[[F; N]; N], b: &[F; N]) -> Option<[F; N]> {
+    if N == 0 {
+        // The matrix is empty
+        return None;
+    }
+    let (mut m, mut v) = (*a, *b);
+    // Obtaining the row echelon form of the augmented matrix
+    for r in 0..N {
+        if m[r][r] == F::ZERO {
+            // If there is no pivot, then the matrix is singular
+            let p = (r + 1..N).find(|y| m[*y][r] != F::ZERO)?;
+            m.swap(r, p);
+            v.swap(r, p);
+        }
+        let c = m[r][r];
+        m[r][r] = F::ONE;
+        m[r].iter_mut().skip(r + 1).for_each(|e| *e /= c);
+        v[r] /= c;
+        let (u, l) = m.split_at_mut(r + 1);
+        for (s, y) in l.iter_mut().zip(r + 1..N) {
+            let c = s[r];
+            s[r] = F::ZERO;
+            for (e, t) in s.iter_mut().zip(u[r].iter()).skip(r + 1) {
+                *e -= c * t;
+            }
+            v[y] -= c * v[r];
+        }
+    }
+    // Transforming the right-hand side into the sought column vector
+    for r in (1..N).rev() {
+        for y in 0..r {
+            v[y] -= m[y][r] * v[r];
+        }
+    }
+    Some(v)
+}
+
+// The maximum number of the distinct prime divisors of the matrix order, since the
+// orders, which have more of them, exceed 2 * 3 * 5 * 7 * 11 * 13 * 17 * 19 * 23,
+// and the matrices of such orders cannot be stored on the stack
+const MAX_PRIME_DIVISORS: usize = 8;
+
+// Applies the MDSECheck method as the function "crate::security_level" does
+fn checked_level<F: PrimeField, const N: usize>(a: &[[F; N]; N], l: u32) -> Option<u32> {
+    if (N < 2) || (l == 0) {
+        // The matrix cannot be used in P-SPN, or the second argument is 0
+        return None;
+    }
+    // Using the Krylov method fragment, whose vectors are the columns of the system matrix
+    // except for the last one, which is represented as their linear combination
+    let mut m = [[F::ZERO; N]; N];
+    let mut b = [F::ONE; N];
+    for i in 0..N {
+        for (r, e) in m.iter_mut().zip(b.iter()) {
+            r[i] = *e;
+        }
+        b = product_vector(a, &b);
+    }
+    // If the Krylov vectors are linearly dependent, then
+    // the matrix is not unconditionally P-SPN secure
+    let s = system_solution(&m, &b)?;
+    // The minimal polynomial is x^N - s[N - 1] * x^(N - 1) - ... - s[0]
+    let c = s.map(|e| -e);
+    if !stickelberger_admissibility(&c) {
+        return None;
+    }
+    // Checking the irreducibility of the minimal polynomial by means of the Frobenius
+    // matrix, whose j-th column is x^(j * p) modulo the polynomial, and saving the values
+    // x^(p^d) for the degrees d of the maximal subfields of the splitting field
+    let mut x = [F::ZERO; N];
+    x[1] = F::ONE;
+    let h = modular_power(&x, F::characteristic(), &c);
+    let (mut t, mut v) = ([[F::ZERO; N]; N], [F::ZERO; N]);
+    v[0] = F::ONE;
+    for j in 0..N {
+        for (r, e) in t.iter_mut().zip(v.iter()) {
+            r[j] = *e;
+        }
+        v = modular_product(&v, &h, &c);
+    }
+    let (mut y, mut k) = ([[F::ZERO; N]; MAX_PRIME_DIVISORS], 0);
+    let mut r = x;
+    for d in 1..=N / 2 {
+        r = product_vector(&t, &r);
+        let mut g = r;
+        g[1] -= F::ONE;
+        if !coprimality(&g, &c) {
+            // The minimal polynomial is not irreducible
+            return None;
+        }
+        // The degree d is the one of a maximal subfield, if N / d is prime,
+        // which is checked by means of the trial division without allocating
+        let q = N / d;
+        if N % d == 0 && (2..).take_while(|e| e * e <= q).all(|e| q % e != 0) {
+            y[k] = r;
+            k += 1;
+        }
+    }
+    // Checking that the higher powers of a root of the minimal polynomial
+    // do not belong to the nontrivial subfields of its splitting field
+    let (mut g, mut h) = (x, y);
+    for i in 2..=l {
+        g = modular_product(&g, &x, &c);
+        for (v, u) in h[..k].iter_mut().zip(y[..k].iter()) {
+            *v = modular_product(v, u, &c);
+            if *v == g {
+                return Some(i - 1);
+            }
+        }
+    }
+    Some(l)
+}
+
+// Computes the product of the arguments modulo the monic polynomial
+// of degree N, whose lower coefficients are the third argument
+fn modular_product<F: Field, const N: usize>(a: &[F; N], b: &[F; N], c: &[F; N]) -> [F; N] {
+    let mut r = [F::ZERO; N];
+    // Using the Horner method, where the multiplication by x
+    // uses the equality x^N = -c[N - 1] * x^(N - 1) - ... - c[0]
+    for i in (0..N).rev() {
+        let t = r[N - 1];
+        for k in (1..N).rev() {
+            r[k] = r[k - 1] - t * c[k];
+        }
+        r[0] = -t * c[0];
+        if !a[i].is_zero() {
+            for k in 0..N {
+                r[k] += a[i] * b[k];
+            }
+        }
+    }
+    r
+}
+
+// Computes the first argument raised to the power of the second one modulo the monic
+// polynomial of degree N, whose lower coefficients are the third argument. The second
+// argument is represented by its 64-bit chunks stored in the little-endian order
+fn modular_power<F: Field, const N: usize>(a: &[F; N], e: &[u64], c: &[F; N]) -> [F; N] {
+    let mut r = [F::ZERO; N];
+    r[0] = F::ONE;
+    for b in num::reversed_bits(e) {
+        r = modular_product(&r, &r, c);
+        if b {
+            r = modular_product(&r, a, c);
+        }
+    }
+    r
+}
+
+// Computes the degree of the polynomial, whose coefficients are the argument.
+// If the polynomial is 0, then None is returned
+fn degree<F: Field, const N: usize>(a: &[F; N]) -> Option<usize> {
+    a.iter().rposition(|e| !e.is_zero())
+}
+
+// Computes the first argument modulo the second one, which is nonzero
+fn remainder<F: Field, const N: usize>(a: &[F; N], b: &[F; N]) -> [F; N] {
+    let mut a = *a;
+    let n = degree(b).unwrap();
+    // The leading coefficient of a nonzero polynomial is invertible
+    let l = b[n].inverse().unwrap();
+    for i in (n..N).rev() {
+        let t = a[i] * l;
+        if !t.is_zero() {
+            for k in 0..=n {
+                a[i - n + k] -= t * b[k];
+            }
+        }
+    }
+    a
+}
+
+// Computes the monic polynomial of degree N, whose lower coefficients are the
+// first argument, modulo the second argument, which is nonzero and of lower degree
+fn monic_remainder<F: Field, const N: usize>(c: &[F; N], g: &[F; N]) -> [F; N] {
+    // Since x^N does not fit, it is computed as x * (x^(N - 1) modulo g), and the degree
+    // of the latter is less than the degree of g, which is less than N
+    let mut s = [F::ZERO; N];
+    s[N - 1] = F::ONE;
+    let s = remainder(&s, g);
+    let mut t = [F::ZERO; N];
+    t[1..].copy_from_slice(&s[..N - 1]);
+    for (e, u) in t.iter_mut().zip(c.iter()) {
+        *e += u;
+    }
+    remainder(&t, g)
+}
+
+// Checks whether the first argument and the monic polynomial of degree N,
+// whose lower coefficients are the second argument, are coprime
+fn coprimality<F: Field, const N: usize>(g: &[F; N], c: &[F; N]) -> bool {
+    if degree(g).is_none() {
+        // The greatest common divisor is the monic polynomial itself
+        return false;
+    }
+    let (mut a, mut b) = (*g, monic_remainder(c, g));
+    while degree(&b).is_some() {
+        (a, b) = (b, remainder(&a, &b));
+    }
+    degree(&a) == Some(0)
+}
+
+// Computes the resultant of the arguments as the function "poly::resultant" does
+fn resultant<F: Field, const N: usize>(a: &[F; N], b: &[F; N]) -> F {
+    let (mut a, mut b, mut r) = (*a, *b, F::ONE);
+    loop {
+        let (Some(m), Some(n)) = (degree(&a), degree(&b)) else {
+            return F::ZERO;
+        };
+        if n == 0 {
+            return r * b[0].pow([m as u64]);
+        }
+        if m == 0 {
+            return r * a[0].pow([n as u64]);
+        }
+        let c = remainder(&a, &b);
+        let Some(k) = degree(&c) else {
+            // The arguments have a nontrivial common divisor
+            return F::ZERO;
+        };
+        // Using the equality Res(a, b) = (-1)^(mn) * lc(b)^(m - k) * Res(b, c),
+        // where c is the remainder of a modulo b and k is the degree of c
+        if m * n % 2 == 1 {
+            r = -r;
+        }
+        r *= b[n].pow([(m - k) as u64]);
+        (a, b) = (b, c);
+    }
+}
+
+// Computes the same value as the function "poly::stickelberger_admissibility" for the
+// monic polynomial of degree N, whose lower coefficients are the argument
+fn stickelberger_admissibility<F: PrimeField, const N: usize>(c: &[F; N]) -> bool {
+    // The derivative of the polynomial, whose degree is less than N
+    let mut e = [F::ZERO; N];
+    for i in 1..N {
+        e[i - 1] = c[i] * F::from(i as u64);
+    }
+    e[N - 1] = F::from(N as u64);
+    // Computing the resultant of the polynomial and its derivative, whose first step
+    // is performed separately, since the polynomial of degree N does not fit
+    let r = match degree(&e) {
+        None => F::ZERO,
+        Some(0) => e[0].pow([N as u64]),
+        Some(n) => {
+            let u = monic_remainder(c, &e);
+            match degree(&u) {
+                None => F::ZERO,
+                Some(k) => {
+                    let s = if N * n % 2 == 1 { -F::ONE } else { F::ONE };
+                    s * e[n].pow([(N - k) as u64]) * resultant(&e, &u)
+                }
+            }
+        }
+    };
+    // The discriminant of the monic polynomial, whose sign
+    // is determined by the parity of N(N - 1) / 2
+    let d = if N % 4 < 2 { r } else { -r };
+    if d.is_zero() {
+        // The polynomial has a repeated root
+        return false;
+    }
+    if F::MODULUS == F::BigInt::from(2u64) {
+        return true;
+    }
+    d.legendre().is_qr() == (N % 2 == 1)
+}
@@ -1,126 +1,365 @@
 #![doc = include_str!("../README.md")]
 
-use ark_ff::PrimeField;
-use ark_poly::{polynomial::univariate::DensePolynomial, DenseUVPolynomial};
-use indexmap::IndexSet;
+use ark_ff::{Field, PrimeField};
+use ark_poly::{polynomial::univariate::DensePolynomial, Polynomial};
+use mat::{LinearMap, Rows};
 use rand::Rng;
 
+pub mod estimate;
+pub mod ext;
+pub mod fixed;
 pub mod mat;
 pub mod num;
 pub mod poly;
+pub mod reference;
+pub mod ring;
+pub mod sim;
+pub mod stats;
+pub mod trail;
+
+/// Computes the inverses of the elements of the specified list by means of the Montgomery
+/// trick, i.e. the prefix products of the elements are computed, the last of them is
+/// inverted, and the inverses are obtained from it by means of the multiplications in the
+/// reverse order, so only one field inversion is performed. If some element is 0, then None
+/// is returned.
+pub fn batch_inverse<F: Field>(v: &[F]) -> Option<Vec<F>> {
+    let mut p = Vec::with_capacity(v.len());
+    let mut c = F::ONE;
+    for e in v.iter() {
+        p.push(c);
+        c *= e;
+    }
+    // The product is 0 if and only if some element is 0
+    let mut c = c.inverse()?;
+    for (e, q) in v.iter().zip(p.iter_mut()).rev() {
+        // Here c is the inverse of the product of the elements up to the current one,
+        // and the current entry is the product of the elements preceding it
+        *q *= c;
+        c *= e;
+    }
+    Some(p)
+}
+
+/// Creates the Cauchy square matrix, whose entry in the i-th row and the j-th column
+/// is 1 / (x_j - y_i), where x and y are the first and the second arguments respectively.
+/// Such a matrix is MDS. The differences are inverted by means of the function
+/// "batch_inverse". If the arguments are empty or have different lengths or their
+/// entries are not pairwise distinct, then None is returned.
+pub fn cauchy<F: PrimeField>(x: &[F], y: &[F]) -> Option<Vec<Vec<F>>> {
+    Some(mat::CauchyMatrix::new(x, y)?.entries())
+}
+
+/// Computes the list of the pairs, each of which consists of a monic irreducible factor f
+/// of the characteristic polynomial of the i-th power of the specified square matrix M, where
+/// i is the second argument, and the basis of a proper nontrivial subspace invariant under
+/// M^i, which is associated with f. The basis spans the kernel of f(M^i), unless the kernel
+/// is the whole space, in which case it spans the cyclic subspace of the dimension of f,
+/// which is generated by the first standard basis vector. The factorisation uses the source
+/// of randomness specified by the third argument. If the characteristic polynomial of M^i is
+/// irreducible, then the list is empty, and if the first argument is not a square matrix
+/// or the second argument is 0, then None is returned.
+pub fn invariant_subspaces<F: PrimeField>(
+    a: &(impl Rows<F> + ?Sized),
+    i: u32,
+    r: &mut (impl Rng + ?Sized),
+) -> Option<Vec<InvariantSubspace<F>>> {
+    let n = a.row_count();
+    if i == 0 {
+        return None;
+    }
+    let b = mat::power_matrix(a, &[i as u64])?;
+    let c = mat::characteristic_polynomial(&b)?;
+    let mut s = Vec::new();
+    for (f, _) in poly::factors(&c, r)? {
+        if f.degree() == n {
+            // The characteristic polynomial is irreducible, so
+            // there are no proper nontrivial invariant subspaces
+            break;
+        }
+        let mut k = mat::kernel(&mat::polynomial_value(&f, &b)?)?;
+        if k.len() == n {
+            // The minimal polynomial of M^i is f, so each nonzero vector generates
+            // the cyclic invariant subspace, whose dimension is the degree of f
+            let mut v = vec![F::ZERO; n];
+            v[0] = F::ONE;
+            k = Vec::with_capacity(f.degree());
+            for _ in 0..f.degree() {
+                let u = mat::product_vector(&b, &v)?;
+                k.push(v);
+                v = u;
+            }
+        }
+        s.push((f, k));
+    }
+    Some(s)
+}
+
+// The monic irreducible factor of the characteristic polynomial and
+// the basis of the associated invariant subspace
+type InvariantSubspace<F> = (DensePolynomial<F>, Vec<Vec<F>>);
 
 /// Creates a random Cauchy square MDS matrix, which has the order specified by the first
 /// argument and the entries, which are determined by both the order and the source of
 /// randomness specified by the second argument. If the first argument is 0 or the field
 /// does not have enough elements for the specified matrix order, then None is returned.
 pub fn random_cauchy<F: PrimeField>(n: u32, r: &mut (impl Rng + ?Sized)) -> Option<Vec<Vec<F>>> {
-    if (n == 0) || (F::MODULUS < F::BigInt::from(n as u64 * 2)) {
-        // The first argument is 0 or the field does not have
-        // enough elements for the specified matrix order
-        return None;
-    }
-    let n = n as usize;
-    let mut d = IndexSet::with_capacity(2 * n);
-    for _ in 0..2 * n {
-        while !d.insert(F::rand(r)) {}
-    }
-    let mut m = Vec::with_capacity(n);
-    for y in d.iter().skip(n) {
-        let mut v = Vec::with_capacity(n);
-        for x in d.iter().take(n) {
-            // Since all elements in IndexSet are distinct, for
-            // each pair of them the difference is invertible
-            v.push((*x - y).inverse().unwrap());
-        }
-        m.push(v);
-    }
-    Some(m)
+    Some(mat::CauchyMatrix::random(n, r)?.entries())
 }
 
 /// Computes the largest positive number, which exceeds neither the unconditional P-SPN
 /// security level of the specified MDS matrix, nor the second argument, by means of
 /// the MDSECheck method. The matrix is not checked for being MDS, so it should be
-/// generated properly, e.g. using the tools the crate provides. If the matrix is not
-/// unconditionally P-SPN secure, then None is returned.
-pub fn security_level<F: PrimeField>(a: &[impl AsRef<[F]>], l: u32) -> Option<u32> {
-    let n = a.len();
-    if (n < 2) || (l == 0) {
-        // The first argument is not a matrix, which can
-        // be used in P-SPN, or the second argument is 0
-        return None;
+/// generated properly, e.g. using the tools the crate provides. Since only the products of
+/// the matrix and vectors are used, the matrix may be given by any type implementing the
+/// trait "mat::LinearMap", e.g. "mat::CauchyMatrix". If the matrix is not unconditionally
+/// P-SPN secure, then None is returned.
+pub fn security_level<F: PrimeField>(a: &(impl LinearMap<F> + ?Sized), l: u32) -> Option<u32> {
+    tracked_security_level(a, l, &mut Statistics::default())
+}
+
+/// Applies the MDSECheck method to the matrices of the fixed order with the fixed bound of
+/// the security level, so it is intended for checking many candidate matrices. The prime
+/// divisors of the matrix order are found once on construction, and the buffers for the
+/// Krylov vectors, the linear system, the minimal polynomial, its Frobenius matrix and the
+/// powers of its root, as well as the quotient ring modulo it, are refilled in place by each
+/// check. Thus, once the buffers have grown, the checks allocate only the temporary values
+/// of the Karatsuba method and the number theoretic transform used for the polynomial
+/// products of the large degrees and the ones allocated by the products of the matrix and
+/// vectors, e.g. for "mat::CauchyMatrix". The results are identical to the ones of the
+/// functions "security_level" and "tracked_security_level".
+#[derive(Clone, Debug)]
+pub struct SecurityChecker<F: PrimeField> {
+    n: usize,
+    l: u32,
+    f: Vec<usize>,
+    o: Vec<F>,
+    k: Vec<F>,
+    m: Vec<F>,
+    c: Vec<F>,
+    u: Vec<F>,
+    v: Vec<F>,
+    q: ring::ModRing<F>,
+    b: Vec<F>,
+    r: Vec<F>,
+    w: Vec<F>,
+    g: Vec<F>,
+    y: Vec<Vec<F>>,
+    h: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> SecurityChecker<F> {
+    /// Creates the checker for the matrices of the order specified by the first argument
+    /// and the bound of the security level specified by the second one. If the first
+    /// argument is less than 2 or the second one is 0, then None is returned.
+    pub fn new(n: u32, l: u32) -> Option<Self> {
+        if (n < 2) || (l == 0) {
+            // The matrices cannot be used in P-SPN, or the bound is 0
+            return None;
+        }
+        // The degrees of the maximal subfields of the splitting field
+        let f = num::prime_divisors(n)
+            .into_iter()
+            .map(|e| (n / e) as usize)
+            .collect::<Vec<_>>();
+        let n = n as usize;
+        Some(Self {
+            n,
+            l,
+            // In this case, any non-zero vector can be chosen as the first Krylov vector
+            o: vec![F::ONE; n],
+            k: Vec::with_capacity((n + 1) * n),
+            m: Vec::with_capacity(n * (n + 1)),
+            c: Vec::with_capacity(n + 1),
+            u: Vec::with_capacity(n + 1),
+            v: Vec::with_capacity(n + 1),
+            // The modulus is replaced by the minimal polynomial in each check
+            q: ring::ModRing::new(&poly::new(&[1, 0]))?,
+            b: Vec::with_capacity(n * n),
+            r: Vec::with_capacity(n),
+            w: Vec::with_capacity(n),
+            g: Vec::with_capacity(2 * n),
+            y: vec![Vec::with_capacity(2 * n); f.len()],
+            h: vec![Vec::with_capacity(2 * n); f.len()],
+            f,
+        })
+    }
+
+    /// Returns the bound of the security level.
+    pub fn bound(&self) -> u32 {
+        self.l
+    }
+
+    /// Computes the same value as the function "security_level" for the specified matrix and
+    /// the bound of the checker. If the argument is not a square matrix of the order of the
+    /// checker or it is not unconditionally P-SPN secure, then None is returned.
+    pub fn check(&mut self, a: &(impl LinearMap<F> + ?Sized)) -> Option<u32> {
+        self.tracked_check(a, &mut Statistics::default())
     }
-    // Using the Krylov method fragment, which successfully
-    // computes the minimal polynomial of the matrix, provided
-    // that the polynomial is of maximum degree and irreducible,
-    // fails if the polynomial is not of maximum degree and may
-    // fail in other cases
-    let mut m = Vec::<Vec<F>>::with_capacity(n);
-    // In this case, any non-zero vector can be chosen as the first Krylov vector
-    m.push(vec![F::ONE; n]);
-    for i in 0..n - 1 {
-        // Computing all required subsequent Krylov vectors except the last one
-        m.push(mat::product_vector(a, &m[i])?);
+
+    /// Returns the order of the matrices.
+    pub fn order(&self) -> u32 {
+        self.n as u32
     }
-    // The last Krylov vector is stored separately because it is the one
-    // that will be represented as a linear combination of the previous
-    // Krylov vectors, provided that they are linearly independent
-    let b = mat::product_vector(a, &m[n - 1])?;
-    // Computing the horizontal concatenation of transposed elements of
-    // the computed Krylov vector sequence without its last element
-    for y in 0..n {
-        for x in 0..y {
-            (m[y][x], m[x][y]) = (m[x][y], m[y][x]);
+
+    /// Computes the same value as the function "check" for the first argument and updates
+    /// the statistics specified by the second argument as the function "tracked_security_level"
+    /// does. If the crate feature "audit" is enabled, then the value is compared with the
+    /// output of the function "reference::security_level", and a panic occurs on disagreement.
+    pub fn tracked_check(
+        &mut self,
+        a: &(impl LinearMap<F> + ?Sized),
+        t: &mut Statistics,
+    ) -> Option<u32> {
+        if a.order() != Some(self.n) {
+            // The argument is not a square matrix of the order of the checker
+            return None;
         }
+        let s = self.checked(a, t);
+        #[cfg(feature = "audit")]
+        assert_eq!(
+            s,
+            reference::security_level(a, self.l),
+            "The MDSECheck method disagrees with the reference checker"
+        );
+        s
     }
-    // Computing the coefficients of the aforementioned linear combination. If the
-    // function returns at this point, then the vectors of this linear combination
-    // are linearly dependent, implying that the minimal polynomial of the matrix
-    // cannot be of maximum degree and irreducible, which has allowed the Krylov
-    // method fragment to fail. Thus, the matrix is not unconditionally P-SPN secure
-    let mut s = mat::system_solution(&m, &b)?;
-    // Computing the minimal polynomial from the coefficients of the linear combination
-    s.iter_mut().for_each(|e| *e = -*e);
-    s.push(F::ONE);
-    let c = DensePolynomial::<F>::from_coefficients_vec(s);
-    // Checking the irreducibility of the minimal polynomial, which has been found using
-    // the Krylov method fragment, by means of Algorithm 2.2.9 in the book "Prime Numbers -
-    // A Computational Perspective (2nd edn.)" by R. Crandall and C. Pomerance. Some values
-    // computed in this step will be used in the next one
-    let f = num::prime_divisors(n as u32)
-        .into_iter()
-        .map(|e| n / e as usize)
-        .collect::<IndexSet<usize>>();
-    let mut y: Vec<DensePolynomial<F>> = Vec::<DensePolynomial<F>>::with_capacity(f.len());
-    let x = poly::new(&[1, 0]);
-    let mut r = x.clone();
-    for d in 1..=n / 2 {
-        r = poly::power_modulo(&r, F::characteristic(), &c)?;
-        if !poly::coprimality(&(&r - &x), &c) {
-            // The minimal polynomial is not irreducible
+
+    // Applies the MDSECheck method to the square matrix of the order of the checker
+    // and updates the statistics
+    fn checked(&mut self, a: &(impl LinearMap<F> + ?Sized), t: &mut Statistics) -> Option<u32> {
+        let (n, l) = (self.n, self.l);
+        // Using the Krylov method fragment, which successfully
+        // computes the minimal polynomial of the matrix, provided
+        // that the polynomial is of maximum degree and irreducible,
+        // fails if the polynomial is not of maximum degree and may
+        // fail in other cases. The vectors are stored contiguously
+        self.k.clear();
+        a.krylov_vectors(&self.o, n + 1, &mut self.k);
+        // The last Krylov vector is the one that will be represented as a linear combination
+        // of the previous Krylov vectors, provided that they are linearly independent, so
+        // the vectors are the columns of the augmented matrix of the linear system
+        let w = n + 1;
+        self.m.clear();
+        self.m.resize(n * w, F::ZERO);
+        for i in 0..w {
+            for y in 0..n {
+                self.m[y * w + i] = self.k[i * n + y];
+            }
+        }
+        // Computing the coefficients of the aforementioned linear combination. If the
+        // function returns at this point, then the vectors of this linear combination
+        // are linearly dependent, implying that the minimal polynomial of the matrix
+        // cannot be of maximum degree and irreducible, which has allowed the Krylov
+        // method fragment to fail. Thus, the matrix is not unconditionally P-SPN secure
+        if !mat::augmented_solution(&mut self.m, n) {
+            t.krylov += 1;
             return None;
         }
-        if f.contains(&d) {
-            // Saving a value useful for the next step
-            y.push(r.clone());
+        // Computing the minimal polynomial from the coefficients of the linear combination
+        self.c.clear();
+        self.c.extend((0..n).map(|y| -self.m[y * w + n]));
+        self.c.push(F::ONE);
+        // Applying the Stickelberger parity pre-filter, which cheaply rejects roughly
+        // half of the reducible minimal polynomials by means of the quadratic character
+        // of the discriminant before the expensive irreducibility check
+        if !poly::stickelberger_admissibility_with(&self.c, &mut self.u, &mut self.v) {
+            t.stickelberger += 1;
+            return None;
         }
-    }
-    // Checking that the minimal polynomials for the higher powers of the matrix also are
-    // of maximum degree and irreducible. This is done by checking that the higher powers
-    // of a root of the characteristic polynomial of the matrix do not belong to nontrivial
-    // subfields of the splitting field of the characteristic polynomial. Since the minimal
-    // polynomial of the matrix is of maximum degree, it equals the characteristic polynomial
-    let (mut g, mut h) = (x.clone(), y.clone());
-    for i in 2..=l {
-        g = poly::reduced_modulo(&(&g * &x), &c)?;
-        for (v, u) in h.iter_mut().zip(y.iter()) {
-            *v = poly::reduced_modulo(&(&*v * u), &c)?;
-            if *v == g {
-                // For the current power of the matrix the minimal polynomial is not
-                // of maximum degree or not irreducible, so the unconditional P-SPN
-                // security level of the matrix equals the previous exponent
-                return Some(i - 1);
+        // Checking the irreducibility of the minimal polynomial, which has been found using
+        // the Krylov method fragment, by means of Algorithm 2.2.9 in the book "Prime Numbers -
+        // A Computational Perspective (2nd edn.)" by R. Crandall and C. Pomerance. Some values
+        // computed in this step will be used in the next one
+        self.q.reset(&self.c);
+        // Computing x^p modulo the minimal polynomial once, so that the further Frobenius
+        // images are obtained by means of the modular composition, i.e. the products of the
+        // Frobenius matrix and the coefficient vectors, which are padded to the length n
+        poly::frobenius_matrix_with(&mut self.q, &mut self.b, &mut self.u, &mut self.v);
+        self.r.clear();
+        self.r.resize(n, F::ZERO);
+        self.r[1] = F::ONE;
+        let mut j = 0;
+        for d in 1..=n / 2 {
+            self.w.clear();
+            self.w
+                .extend(self.b.chunks(n).map(|e| mat::dot_product(e, &self.r)));
+            std::mem::swap(&mut self.r, &mut self.w);
+            self.u.clear();
+            self.u.extend_from_slice(&self.r);
+            self.u[1] -= F::ONE;
+            self.v.clear();
+            self.v.extend_from_slice(&self.c);
+            if !poly::coprimality_with(&mut self.u, &mut self.v) {
+                // The minimal polynomial is not irreducible
+                t.reducible += 1;
+                return None;
             }
+            if self.f.contains(&d) {
+                // Saving a value useful for the next step
+                self.y[j].clear();
+                self.y[j].extend_from_slice(&self.r);
+                j += 1;
+            }
+        }
+        // Checking that the minimal polynomials for the higher powers of the matrix also are
+        // of maximum degree and irreducible. This is done by checking that the higher powers
+        // of a root of the characteristic polynomial of the matrix do not belong to nontrivial
+        // subfields of the splitting field of the characteristic polynomial. Since the minimal
+        // polynomial of the matrix is of maximum degree, it equals the characteristic polynomial
+        let x = [F::ZERO, F::ONE];
+        self.g.clear();
+        self.g.extend_from_slice(&x);
+        for (v, u) in self.h.iter_mut().zip(self.y.iter()) {
+            v.clear();
+            v.extend_from_slice(u);
         }
+        for i in 2..=l {
+            self.q.product_assign(&mut self.g, &x);
+            for (v, u) in self.h[..j].iter_mut().zip(self.y.iter()) {
+                self.q.product_assign(v, u);
+                if *v == self.g {
+                    // For the current power of the matrix the minimal polynomial is not
+                    // of maximum degree or not irreducible, so the unconditional P-SPN
+                    // security level of the matrix equals the previous exponent
+                    t.subfield += 1;
+                    return Some(i - 1);
+                }
+            }
+        }
+        t.secure += 1;
+        Some(l)
     }
-    Some(l)
+}
+
+/// Contains the numbers of the matrices checked by means of the function
+/// "tracked_security_level", which are grouped by the outcome of the check.
+/// The arguments, which are not matrices usable in P-SPN, are not counted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Statistics {
+    /// The number of the matrices, for which the Krylov method fragment has failed
+    pub krylov: u64,
+    /// The number of the matrices rejected by the Stickelberger parity pre-filter
+    pub stickelberger: u64,
+    /// The number of the matrices, whose minimal polynomial is reducible
+    pub reducible: u64,
+    /// The number of the unconditionally P-SPN secure matrices,
+    /// whose security level is less than the specified bound
+    pub subfield: u64,
+    /// The number of the matrices, whose security level reaches the specified bound
+    pub secure: u64,
+}
+
+/// Computes the same value as the function "security_level" for the same first two
+/// arguments and updates the statistics specified by the third argument in accordance
+/// with the outcome of the check. If the crate feature "audit" is enabled, then the value
+/// is compared with the output of the function "reference::security_level", and a panic
+/// occurs on disagreement. By design, a new instance of "SecurityChecker" is created for
+/// each call, so that the function does not keep any state, and the checker itself should
+/// be used for checking many matrices of the same order.
+pub fn tracked_security_level<F: PrimeField>(
+    a: &(impl LinearMap<F> + ?Sized),
+    l: u32,
+    t: &mut Statistics,
+) -> Option<u32> {
+    SecurityChecker::new(u32::try_from(a.order()?).ok()?, l)?.tracked_check(a, t)
 }
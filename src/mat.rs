@@ -1,50 +1,708 @@
 //! Provides auxiliary tools for working with matrices.
 
-use ark_ff::Field;
+use std::sync::OnceLock;
 
-/// Computes the matrix product of the arguments. If the arguments are
-/// not matrices for which the product is defined, then None is returned.
+use ark_ff::{Field, PrimeField};
+use ark_poly::{polynomial::univariate::DensePolynomial, DenseUVPolynomial};
+use indexmap::IndexSet;
+use rand::Rng;
+
+use crate::{poly, ring};
+
+/// Represents a nonempty matrix, whose entries are stored contiguously in the row-major
+/// order, so that the shape is checked once on construction and the rows do not require
+/// separate allocations. The functions of the module accept it by means of the trait
+/// "Rows", and the arithmetic operators, which return None for the incompatible shapes,
+/// are implemented for the references to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix<F: Field> {
+    h: usize,
+    w: usize,
+    e: Vec<F>,
+}
+
+impl<F: Field> Matrix<F> {
+    /// Creates the matrix from the specified list of its rows. If the argument is empty
+    /// or its elements are empty or have different lengths, then None is returned.
+    pub fn new(a: &(impl Rows<F> + ?Sized)) -> Option<Self> {
+        let w = a.row_length()?;
+        if w == 0 {
+            return None;
+        }
+        Some(Self {
+            h: a.row_count(),
+            w,
+            e: a.rows().flatten().copied().collect(),
+        })
+    }
+
+    /// Creates the matrix of the specified height and width from the list of its entries
+    /// in the row-major order. If the height or the width is 0 or the number of the entries
+    /// is not their product, then None is returned.
+    pub fn from_entries(h: usize, w: usize, e: Vec<F>) -> Option<Self> {
+        if (h == 0) || (w == 0) || (h.checked_mul(w)? != e.len()) {
+            return None;
+        }
+        Some(Self { h, w, e })
+    }
+
+    /// Creates the identity matrix of the specified order.
+    /// If the argument is 0, then None is returned.
+    pub fn identity(n: usize) -> Option<Self> {
+        let mut m = Self::from_entries(n, n, vec![F::ZERO; n * n])?;
+        for i in 0..n {
+            m[(i, i)] = F::ONE;
+        }
+        Some(m)
+    }
+
+    /// Returns the number of the rows.
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    /// Returns the number of the columns.
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    /// Returns the entries in the row-major order.
+    pub fn entries(&self) -> &[F] {
+        &self.e
+    }
+
+    /// Creates the list of the rows of the matrix.
+    pub fn to_rows(&self) -> Vec<Vec<F>> {
+        self.e.chunks(self.w).map(|r| r.to_vec()).collect()
+    }
+
+    /// Creates the transpose of the matrix.
+    pub fn transpose(&self) -> Self {
+        let mut e = Vec::with_capacity(self.e.len());
+        for x in 0..self.w {
+            e.extend((0..self.h).map(|y| self.e[y * self.w + x]));
+        }
+        Self {
+            h: self.w,
+            w: self.h,
+            e,
+        }
+    }
+}
+
+impl<F: Field> std::ops::Index<usize> for Matrix<F> {
+    type Output = [F];
+
+    fn index(&self, y: usize) -> &[F] {
+        &self.e[y * self.w..(y + 1) * self.w]
+    }
+}
+
+impl<F: Field> std::ops::IndexMut<usize> for Matrix<F> {
+    fn index_mut(&mut self, y: usize) -> &mut [F] {
+        &mut self.e[y * self.w..(y + 1) * self.w]
+    }
+}
+
+impl<F: Field> std::ops::Index<(usize, usize)> for Matrix<F> {
+    type Output = F;
+
+    fn index(&self, (y, x): (usize, usize)) -> &F {
+        &self[y][x]
+    }
+}
+
+impl<F: Field> std::ops::IndexMut<(usize, usize)> for Matrix<F> {
+    fn index_mut(&mut self, (y, x): (usize, usize)) -> &mut F {
+        &mut self[y][x]
+    }
+}
+
+impl<F: Field> std::ops::Add for &Matrix<F> {
+    type Output = Option<Matrix<F>>;
+
+    fn add(self, o: Self) -> Option<Matrix<F>> {
+        if (self.h != o.h) || (self.w != o.w) {
+            return None;
+        }
+        let e = self.e.iter().zip(o.e.iter()).map(|(x, y)| *x + y);
+        Matrix::from_entries(self.h, self.w, e.collect())
+    }
+}
+
+impl<F: Field> std::ops::Sub for &Matrix<F> {
+    type Output = Option<Matrix<F>>;
+
+    fn sub(self, o: Self) -> Option<Matrix<F>> {
+        if (self.h != o.h) || (self.w != o.w) {
+            return None;
+        }
+        let e = self.e.iter().zip(o.e.iter()).map(|(x, y)| *x - y);
+        Matrix::from_entries(self.h, self.w, e.collect())
+    }
+}
+
+impl<F: Field> std::ops::Neg for &Matrix<F> {
+    type Output = Matrix<F>;
+
+    fn neg(self) -> Matrix<F> {
+        Matrix {
+            h: self.h,
+            w: self.w,
+            e: self.e.iter().map(|x| -*x).collect(),
+        }
+    }
+}
+
+impl<F: Field> std::ops::Mul<F> for &Matrix<F> {
+    type Output = Matrix<F>;
+
+    fn mul(self, c: F) -> Matrix<F> {
+        Matrix {
+            h: self.h,
+            w: self.w,
+            e: self.e.iter().map(|x| *x * c).collect(),
+        }
+    }
+}
+
+impl<F: Field> std::ops::Mul for &Matrix<F> {
+    type Output = Option<Matrix<F>>;
+
+    fn mul(self, o: Self) -> Option<Matrix<F>> {
+        if self.w != o.h {
+            return None;
+        }
+        Matrix::from_entries(self.h, o.w, tiled_product(self, o, o.w))
+    }
+}
+
+impl<F: Field> std::ops::Mul<&[F]> for &Matrix<F> {
+    type Output = Option<Vec<F>>;
+
+    fn mul(self, v: &[F]) -> Option<Vec<F>> {
+        product_vector(self, v)
+    }
+}
+
+/// Represents a Cauchy square matrix, whose entry in the i-th row and the j-th column is
+/// 1 / (x_j - y_i), by means of the 2n distinct points x and y, where n is the order. The
+/// explicit entries are computed on demand, and the products of the matrix and column vectors
+/// are computed by means of the trait "LinearMap" without them. For the large orders, the
+/// product is computed as the values -N(y_i) / Q(y_i), where N(z) / Q(z) is the sum of the
+/// fractions v_j / (z - x_j), whose numerator and denominator are computed by means of the
+/// divide-and-conquer method, and the values are computed by means of the remainder tree,
+/// so O(n log^2 n) operations are required, if the fast polynomial multiplication is used.
+/// The subproduct trees of the points and the reciprocals used by the remainder tree do
+/// not depend on the vector, so they are computed by the first such product and reused.
+#[derive(Clone, Debug)]
+pub struct CauchyMatrix<F: PrimeField> {
+    x: Vec<F>,
+    y: Vec<F>,
+    t: OnceLock<CauchyTree<F>>,
+}
+
+impl<F: PrimeField> CauchyMatrix<F> {
+    /// Creates the matrix from the points x and y specified by the first and the second
+    /// arguments respectively. If the arguments are empty or have different lengths or
+    /// their entries are not pairwise distinct, then None is returned.
+    pub fn new(x: &[F], y: &[F]) -> Option<Self> {
+        if x.is_empty() || (x.len() != y.len()) {
+            return None;
+        }
+        let d = x.iter().chain(y.iter()).collect::<IndexSet<_>>();
+        if d.len() != 2 * x.len() {
+            // Some points coincide
+            return None;
+        }
+        Some(Self {
+            x: x.to_vec(),
+            y: y.to_vec(),
+            t: OnceLock::new(),
+        })
+    }
+
+    /// Creates the random matrix, which has the order specified by the first argument and
+    /// the points, which are determined by both the order and the source of randomness
+    /// specified by the second argument, so that its entries are the same as the ones of
+    /// the output of the function "crate::random_cauchy" for the same arguments. If the first
+    /// argument is 0 or the field does not have enough elements for the specified matrix
+    /// order, then None is returned.
+    pub fn random(n: u32, r: &mut (impl Rng + ?Sized)) -> Option<Self> {
+        if (n == 0) || (F::MODULUS < F::BigInt::from(n as u64 * 2)) {
+            // The first argument is 0 or the field does not have
+            // enough elements for the specified matrix order
+            return None;
+        }
+        let n = n as usize;
+        let mut d = IndexSet::with_capacity(2 * n);
+        for _ in 0..2 * n {
+            while !d.insert(F::rand(r)) {}
+        }
+        let mut x = d.into_iter().collect::<Vec<_>>();
+        let y = x.split_off(n);
+        // Since all elements in IndexSet are distinct, the points are valid
+        Some(Self {
+            x,
+            y,
+            t: OnceLock::new(),
+        })
+    }
+
+    /// Returns the points x, which determine the columns.
+    pub fn x(&self) -> &[F] {
+        &self.x
+    }
+
+    /// Returns the points y, which determine the rows.
+    pub fn y(&self) -> &[F] {
+        &self.y
+    }
+
+    /// Computes the entry in the specified row and column, whose indices are
+    /// less than the order. If some index is not, then None is returned.
+    pub fn entry(&self, i: usize, j: usize) -> Option<F> {
+        // Since all points are distinct, each difference is invertible
+        Some((*self.x.get(j)? - self.y.get(i)?).inverse().unwrap())
+    }
+
+    /// Computes the explicit entries of the matrix, which are the same as the ones of the
+    /// output of the function "crate::cauchy" for the points of the matrix, by means of
+    /// the function "crate::batch_inverse".
+    pub fn entries(&self) -> Vec<Vec<F>> {
+        let v = self
+            .y
+            .iter()
+            .flat_map(|b| self.x.iter().map(move |a| *a - b))
+            .collect::<Vec<_>>();
+        // Since all points are distinct, each difference is invertible
+        let v = crate::batch_inverse(&v).unwrap();
+        v.chunks(self.x.len()).map(|r| r.to_vec()).collect()
+    }
+}
+
+impl<F: PrimeField> PartialEq for CauchyMatrix<F> {
+    fn eq(&self, other: &Self) -> bool {
+        // The cached data is determined by the points
+        (self.x == other.x) && (self.y == other.y)
+    }
+}
+
+impl<F: PrimeField> Eq for CauchyMatrix<F> {}
+
+impl<F: PrimeField> LinearMap<F> for CauchyMatrix<F> {
+    fn order(&self) -> Option<usize> {
+        Some(self.x.len())
+    }
+
+    fn image(&self, v: &[F], w: &mut [F]) {
+        if self.x.len() < CAUCHY_FAST_THRESHOLD {
+            // Summing the fractions v_j / (x_j - y_i) as a single fraction,
+            // so only one inversion is performed for each row
+            for (e, b) in w.iter_mut().zip(self.y.iter()) {
+                let (mut p, mut q) = (F::ZERO, F::ONE);
+                for (a, c) in self.x.iter().zip(v.iter()) {
+                    let d = *a - b;
+                    p = p * d + q * c;
+                    q *= d;
+                }
+                // Since all points are distinct, the denominator is invertible
+                *e = p * q.inverse().unwrap();
+            }
+            return;
+        }
+        let c = self.t.get_or_init(|| CauchyTree::new(&self.x, &self.y));
+        let p = c.values(&c.numerator(v).coeffs, &self.y);
+        for (e, (a, b)) in w.iter_mut().zip(p.iter().zip(c.d.iter())) {
+            *e = *a * b;
+        }
+    }
+}
+
+/// Provides the access to the rows of the matrices, which allows the functions of the
+/// module to accept both the lists of the rows, such as arrays, slices and vectors of
+/// arrays, slices or vectors, and the type "Matrix".
+pub trait Rows<F> {
+    /// Returns the number of the rows.
+    fn row_count(&self) -> usize;
+
+    /// Returns the row with the specified index, which is less than the number of the rows.
+    fn row(&self, i: usize) -> &[F];
+
+    /// Returns the common length of the rows. If there are no rows
+    /// or they have different lengths, then None is returned.
+    fn row_length(&self) -> Option<usize> {
+        let w = (self.row_count() > 0).then(|| self.row(0).len())?;
+        self.rows().all(|r| r.len() == w).then_some(w)
+    }
+
+    /// Creates an iterator over the rows.
+    fn rows<'a>(&'a self) -> impl Iterator<Item = &'a [F]>
+    where
+        F: 'a,
+    {
+        (0..self.row_count()).map(|i| self.row(i))
+    }
+}
+
+impl<F, R: AsRef<[F]>> Rows<F> for [R] {
+    fn row_count(&self) -> usize {
+        self.len()
+    }
+
+    fn row(&self, i: usize) -> &[F] {
+        self[i].as_ref()
+    }
+}
+
+impl<F, R: AsRef<[F]>, const N: usize> Rows<F> for [R; N] {
+    fn row_count(&self) -> usize {
+        N
+    }
+
+    fn row(&self, i: usize) -> &[F] {
+        self[i].as_ref()
+    }
+}
+
+impl<F, R: AsRef<[F]>> Rows<F> for Vec<R> {
+    fn row_count(&self) -> usize {
+        self.len()
+    }
+
+    fn row(&self, i: usize) -> &[F] {
+        self[i].as_ref()
+    }
+}
+
+impl<F: Field> Rows<F> for Matrix<F> {
+    fn row_count(&self) -> usize {
+        self.h
+    }
+
+    fn row(&self, i: usize) -> &[F] {
+        &self[i]
+    }
+
+    fn row_length(&self) -> Option<usize> {
+        // The shape has been checked on construction
+        Some(self.w)
+    }
+}
+
+/// Provides the products of a square matrix and column vectors without the access to its
+/// entries, which allows the matrices stored implicitly, such as the type "CauchyMatrix",
+/// to be used as black boxes, e.g. by the function "crate::security_level". The trait is
+/// implemented for all types, which implement the trait "Rows", in the same way.
+pub trait LinearMap<F: Field> {
+    /// Returns the order of the matrix. If the matrix is
+    /// empty or not square, then None is returned.
+    fn order(&self) -> Option<usize>;
+
+    /// Writes the product of the matrix and the column vector specified by the first
+    /// argument into the second argument, where both arguments have the length equal
+    /// to the order of the matrix.
+    fn image(&self, v: &[F], w: &mut [F]);
+
+    /// Computes the product of the matrix and the specified column vector. If the order
+    /// of the matrix does not exist or differs from the length of the vector, then None
+    /// is returned.
+    fn product(&self, v: &[F]) -> Option<Vec<F>> {
+        if self.order()? != v.len() {
+            return None;
+        }
+        let mut w = vec![F::ZERO; v.len()];
+        self.image(v, &mut w);
+        Some(w)
+    }
+
+    /// Appends the Krylov vectors v, Av, ..., A^(k - 1)v to the third argument, where A is
+    /// the matrix, and v and k are the first and the second arguments respectively, and v
+    /// has the length equal to the order of the matrix. The vectors are computed sequentially
+    /// by means of the method "image".
+    fn krylov_vectors(&self, v: &[F], k: usize, w: &mut Vec<F>) {
+        let (n, s) = (v.len(), w.len());
+        w.extend_from_slice(v);
+        w.resize(s + k.max(1) * n, F::ZERO);
+        for i in 1..k {
+            let (p, c) = w[s + (i - 1) * n..s + (i + 1) * n].split_at_mut(n);
+            self.image(p, c);
+        }
+        w.truncate(s + k * n);
+    }
+}
+
+impl<F: Field, R: AsRef<[F]>> LinearMap<F> for [R] {
+    fn order(&self) -> Option<usize> {
+        rows_order(self)
+    }
+
+    fn image(&self, v: &[F], w: &mut [F]) {
+        rows_image(self, v, w)
+    }
+}
+
+impl<F: Field, R: AsRef<[F]>, const N: usize> LinearMap<F> for [R; N] {
+    fn order(&self) -> Option<usize> {
+        rows_order(self)
+    }
+
+    fn image(&self, v: &[F], w: &mut [F]) {
+        rows_image(self, v, w)
+    }
+}
+
+impl<F: Field, R: AsRef<[F]>> LinearMap<F> for Vec<R> {
+    fn order(&self) -> Option<usize> {
+        rows_order(self)
+    }
+
+    fn image(&self, v: &[F], w: &mut [F]) {
+        rows_image(self, v, w)
+    }
+}
+
+impl<F: Field> LinearMap<F> for Matrix<F> {
+    fn order(&self) -> Option<usize> {
+        rows_order(self)
+    }
+
+    fn image(&self, v: &[F], w: &mut [F]) {
+        rows_image(self, v, w)
+    }
+}
+
+/// Computes the characteristic polynomial of the specified square matrix by means of
+/// reducing it to the upper Hessenberg form, which is similar to the matrix, and applying
+/// the recurrence for the characteristic polynomials of the leading principal submatrices
+/// of the Hessenberg form. If the argument is not a square matrix, then None is returned.
+pub fn characteristic_polynomial<F: Field>(
+    a: &(impl Rows<F> + ?Sized),
+) -> Option<DensePolynomial<F>> {
+    let n = a.row_count();
+    if a.row_length() != Some(n) {
+        // The argument is not a square matrix
+        return None;
+    }
+    let mut h = a.rows().map(|s| s.to_vec()).collect::<Vec<_>>();
+    // Obtaining the upper Hessenberg form by means of similarity transformations
+    for c in 0..n.saturating_sub(2) {
+        if let Some(p) = (c + 1..n).find(|y| h[*y][c] != F::ZERO) {
+            h.swap(c + 1, p);
+            for s in h.iter_mut() {
+                s.swap(c + 1, p);
+            }
+        } else {
+            continue;
+        }
+        let v = h[c + 1][c].inverse().unwrap();
+        for y in c + 2..n {
+            let u = h[y][c] * v;
+            if u == F::ZERO {
+                continue;
+            }
+            let (p, q) = h.split_at_mut(y);
+            for (e, t) in q[0].iter_mut().zip(p[c + 1].iter()).skip(c) {
+                *e -= u * *t;
+            }
+            for s in h.iter_mut() {
+                let t = s[y];
+                s[c + 1] += u * t;
+            }
+        }
+    }
+    // Computing the characteristic polynomials of the leading principal submatrices of
+    // the Hessenberg form, which are represented by the little-endian coefficient lists
+    let mut p = Vec::<Vec<F>>::with_capacity(n + 1);
+    p.push(vec![F::ONE]);
+    for m in 1..=n {
+        let mut q = vec![F::ZERO; m + 1];
+        for (i, e) in p[m - 1].iter().enumerate() {
+            q[i + 1] += e;
+            q[i] -= h[m - 1][m - 1] * e;
+        }
+        let mut t = F::ONE;
+        for i in (1..m).rev() {
+            t *= h[i][i - 1];
+            let u = t * h[i - 1][m - 1];
+            for (j, e) in p[i - 1].iter().enumerate() {
+                q[j] -= u * e;
+            }
+        }
+        p.push(q);
+    }
+    Some(DensePolynomial::from_coefficients_vec(p.pop().unwrap()))
+}
+
+/// Computes the ascending list of the distinct eigenvalues of the specified square
+/// matrix, which belong to the field, paired with the bases of the corresponding
+/// eigenspaces. The eigenvalues are the roots of the characteristic polynomial, which
+/// are found by means of the function "poly::roots" using the source of randomness
+/// specified by the second argument, and the eigenspaces are the kernels computed by
+/// means of the function "kernel". If the first argument is not a square matrix, then
+/// None is returned.
+pub fn eigenpairs<F: PrimeField>(
+    a: &(impl Rows<F> + ?Sized),
+    r: &mut (impl Rng + ?Sized),
+) -> Option<Vec<(F, Vec<Vec<F>>)>> {
+    let c = characteristic_polynomial(a)?;
+    let mut e = Vec::new();
+    for v in poly::roots(&c, r)? {
+        let mut m = a.rows().map(|s| s.to_vec()).collect::<Vec<_>>();
+        for (i, s) in m.iter_mut().enumerate() {
+            s[i] -= v;
+        }
+        e.push((v, kernel(&m)?));
+    }
+    Some(e)
+}
+
+/// Computes the basis of the kernel of the specified matrix, i.e. the set of the column
+/// vectors, whose products with the matrix are zero vectors, by means of the Gauss-Jordan
+/// elimination method. Each basis vector has the entry 1 at the position of some non-pivot
+/// column of the reduced row echelon form of the matrix and the entries 0 at the positions
+/// of the other non-pivot columns. If the argument is not a matrix, then None is returned.
+pub fn kernel<F: Field>(a: &(impl Rows<F> + ?Sized)) -> Option<Vec<Vec<F>>> {
+    let w = a.row_length()?;
+    if w == 0 {
+        // The argument is not a matrix
+        return None;
+    }
+    let mut m = a.rows().map(|s| s.to_vec()).collect::<Vec<_>>();
+    // Obtaining the reduced row echelon form and the list of the pivot columns
+    let mut p = Vec::with_capacity(w);
+    for x in 0..w {
+        let r = p.len();
+        if r == m.len() {
+            break;
+        }
+        if let Some(y) = (r..m.len()).find(|y| m[*y][x] != F::ZERO) {
+            m.swap(r, y);
+        } else {
+            continue;
+        }
+        let c = m[r][x].inverse().unwrap();
+        m[r].iter_mut().skip(x).for_each(|e| *e *= c);
+        // Taking the pivot row out, so that the other rows can be modified
+        let s = std::mem::take(&mut m[r]);
+        for (y, u) in m.iter_mut().enumerate() {
+            if (y != r) && (u[x] != F::ZERO) {
+                let c = u[x];
+                for (e, t) in u.iter_mut().zip(s.iter()).skip(x) {
+                    *e -= c * t;
+                }
+            }
+        }
+        m[r] = s;
+        p.push(x);
+    }
+    let mut k = Vec::with_capacity(w - p.len());
+    for f in (0..w).filter(|x| !p.contains(x)) {
+        let mut v = vec![F::ZERO; w];
+        v[f] = F::ONE;
+        for (r, x) in p.iter().enumerate() {
+            v[*x] = -m[r][f];
+        }
+        k.push(v);
+    }
+    Some(k)
+}
+
+/// Computes the value of the specified polynomial at the specified square matrix by
+/// means of the Horner method. If the second argument is not a square matrix, then
+/// None is returned.
+pub fn polynomial_value<F: Field>(
+    p: &DensePolynomial<F>,
+    a: &(impl Rows<F> + ?Sized),
+) -> Option<Vec<Vec<F>>> {
+    let n = a.row_count();
+    if a.row_length() != Some(n) {
+        // The second argument is not a square matrix
+        return None;
+    }
+    let mut m = vec![vec![F::ZERO; n]; n];
+    for c in p.coeffs.iter().rev() {
+        m = product_matrix(&m, a)?;
+        for (i, s) in m.iter_mut().enumerate() {
+            s[i] += c;
+        }
+    }
+    Some(m)
+}
+
+/// Computes the specified square matrix raised to the power of the second argument by
+/// means of the left-to-right binary exponentiation method. The second argument is an
+/// unsigned integer represented by its 64-bit chunks stored in the little-endian order.
+/// If the first argument is not a square matrix, then None is returned.
+pub fn power_matrix<F: Field>(a: &(impl Rows<F> + ?Sized), e: &[u64]) -> Option<Vec<Vec<F>>> {
+    let n = a.row_count();
+    if a.row_length() != Some(n) {
+        // The first argument is not a square matrix
+        return None;
+    }
+    let mut m = (0..n)
+        .map(|y| {
+            (0..n)
+                .map(|x| if x == y { F::ONE } else { F::ZERO })
+                .collect()
+        })
+        .collect::<Vec<Vec<F>>>();
+    for b in crate::num::reversed_bits(e) {
+        m = product_matrix(&m, &m)?;
+        if b {
+            m = product_matrix(&m, a)?;
+        }
+    }
+    Some(m)
+}
+
+/// Computes the matrix product of the arguments. The columns of the second argument are
+/// made contiguous, and the entries are computed by the square tiles by means of the sums
+/// of the products, which are reduced once per chunk rather than once per product. If the
+/// arguments are not matrices for which the product is defined, then None is returned.
 pub fn product_matrix<F: Field>(
-    a: &[impl AsRef<[F]>],
-    b: &[impl AsRef<[F]>],
+    a: &(impl Rows<F> + ?Sized),
+    b: &(impl Rows<F> + ?Sized),
 ) -> Option<Vec<Vec<F>>> {
-    let k = b.len();
-    if (k == 0) || a.is_empty() {
+    let k = b.row_count();
+    if (k == 0) || (a.row_count() == 0) {
         // Some matrix is empty
         return None;
     }
-    let c = b[0].as_ref().len();
-    if a.iter().any(|s| s.as_ref().len() != k) || (0..k).any(|y| b[y].as_ref().len() != c) {
+    let c = b.row_length();
+    if (a.row_length() != Some(k)) || c.is_none() {
         // The arguments are not matrices for which the product is defined
         return None;
     }
-    let mut m = Vec::<Vec<F>>::with_capacity(a.len());
-    for s in a.iter() {
-        let mut l = Vec::<F>::with_capacity(c);
-        let s = s.as_ref();
-        for x in 0..c {
-            l.push((0..k).map(|i| s[i] * b[i].as_ref()[x]).sum());
-        }
-        m.push(l);
+    let c = c?;
+    if c == 0 {
+        return Some(vec![Vec::new(); a.row_count()]);
     }
-    Some(m)
+    Some(
+        tiled_product(a, b, c)
+            .chunks(c)
+            .map(|r| r.to_vec())
+            .collect(),
+    )
 }
 
-/// Computes the product of the specified matrix and column vector. If the arguments
-/// are not a matrix-vector pair for which the product is defined, then None is returned.
-pub fn product_vector<F: Field>(a: &[impl AsRef<[F]>], b: &[F]) -> Option<Vec<F>> {
+/// Computes the product of the specified matrix and column vector by means of the sums of
+/// the products, which are reduced once per chunk rather than once per product. If the
+/// arguments are not a matrix-vector pair for which the product is defined, then None
+/// is returned.
+pub fn product_vector<F: Field>(a: &(impl Rows<F> + ?Sized), b: &[F]) -> Option<Vec<F>> {
     let k = b.len();
-    if (k == 0) || a.is_empty() {
+    if (k == 0) || (a.row_count() == 0) {
         // Either the matrix or the vector is empty
         return None;
     }
-    if a.iter().any(|s| s.as_ref().len() != k) {
+    if a.row_length() != Some(k) {
         // The arguments are not a matrix-vector pair for which the product is defined
         return None;
     }
-    let mut v = Vec::<F>::with_capacity(a.len());
-    for s in a.iter() {
-        v.push(s.as_ref().iter().zip(b.iter()).map(|(x, y)| *x * y).sum());
+    let mut v = Vec::<F>::with_capacity(a.row_count());
+    for s in a.rows() {
+        v.push(dot_product(s, b));
     }
     Some(v)
 }
@@ -53,8 +711,8 @@ pub fn product_vector<F: Field>(a: &[impl AsRef<[F]>], b: &[F]) -> Option<Vec<F>
 /// matrix and it is equal to the specified column vector by means of the Gaussian
 /// elimination method. If the first argument is not a nonsingular matrix of the
 /// height of the specified vector, then None is returned.
-pub fn system_solution<F: Field>(a: &[impl AsRef<[F]>], b: &[F]) -> Option<Vec<F>> {
-    let n = a.len();
+pub fn system_solution<F: Field>(a: &(impl Rows<F> + ?Sized), b: &[F]) -> Option<Vec<F>> {
+    let n = a.row_count();
     if n == 0 {
         // The matrix is empty
         return None;
@@ -63,35 +721,46 @@ pub fn system_solution<F: Field>(a: &[impl AsRef<[F]>], b: &[F]) -> Option<Vec<F
         // The heights of the matrix and column vector are different
         return None;
     }
-    let mut m = Vec::<Vec<F>>::with_capacity(n);
-    for (r, v) in a.iter().zip(b.iter()) {
-        let r = r.as_ref();
+    let mut m = Vec::<F>::with_capacity(n * (n + 1));
+    for (r, v) in a.rows().zip(b.iter()) {
         if r.len() != n {
             // The first argument is not a square matrix
             return None;
         }
-        m.push([r, &[*v][..]].concat());
+        m.extend_from_slice(r);
+        m.push(*v);
     }
+    augmented_solution(&mut m, n).then(|| (0..n).map(|y| m[y * (n + 1) + n]).collect())
+}
+
+// Transforms the specified augmented matrix of the system of the specified order, whose
+// entries are stored in the row-major order, by means of the Gaussian elimination method,
+// so that its rightmost column is the solution. If the system matrix is singular, then
+// false is returned, and the augmented matrix is left partially transformed
+pub(crate) fn augmented_solution<F: Field>(m: &mut [F], n: usize) -> bool {
+    let w = n + 1;
     // Obtaining the row echelon form of the augmented matrix
     for r in 0..n {
-        if m[r][r] == F::ZERO {
-            if let Some(p) = (r + 1..n).find(|y| m[*y][r] != F::ZERO) {
-                m.swap(r, p);
+        if m[r * w + r] == F::ZERO {
+            if let Some(p) = (r + 1..n).find(|y| m[*y * w + r] != F::ZERO) {
+                for x in r..w {
+                    m.swap(r * w + x, p * w + x);
+                }
             } else {
                 // The matrix is singular
-                return None;
+                return false;
             }
         }
-        let c = m[r][r];
-        m[r][r] = F::ONE;
-        for x in r + 1..=n {
-            m[r][x] /= c;
+        let c = m[r * w + r];
+        m[r * w + r] = F::ONE;
+        for x in r + 1..w {
+            m[r * w + x] /= c;
         }
         for y in r + 1..n {
-            let c = m[y][r];
-            m[y][r] = F::ZERO;
-            for x in r + 1..=n {
-                m[y][x] = m[y][x] - c * m[r][x];
+            let c = m[y * w + r];
+            m[y * w + r] = F::ZERO;
+            for x in r + 1..w {
+                m[y * w + x] -= c * m[r * w + x];
             }
         }
     }
@@ -99,8 +768,213 @@ pub fn system_solution<F: Field>(a: &[impl AsRef<[F]>], b: &[F]) -> Option<Vec<F
     // echelon matrix into the sought column vector
     for r in (1..n).rev() {
         for y in 0..r {
-            m[y][n] = m[y][n] - m[y][r] * m[r][n];
+            m[y * w + n] -= m[y * w + r] * m[r * w + n];
+        }
+    }
+    true
+}
+
+// The number of the products, which are accumulated by means of the function
+// "Field::sum_of_products", so that the Montgomery reduction is performed once
+const PRODUCT_CHUNK: usize = 16;
+
+// The order of the square tiles, by which the entries of the matrix products are computed,
+// so that the involved rows of the first factor and columns of the second one stay cached
+const PRODUCT_TILE: usize = 32;
+
+// Computes the sum of the products of the corresponding entries of the arguments, whose
+// lengths are equal, by the chunks, and the products in the last incomplete chunk are
+// reduced separately, since padding it with zeros is slower for the small matrices
+pub(crate) fn dot_product<F: Field>(a: &[F], b: &[F]) -> F {
+    let (u, v) = (a.chunks_exact(PRODUCT_CHUNK), b.chunks_exact(PRODUCT_CHUNK));
+    let (p, q) = (u.remainder(), v.remainder());
+    // The chunks are of the length PRODUCT_CHUNK, so they can be converted into arrays
+    u.zip(v)
+        .map(|(x, y)| {
+            F::sum_of_products::<PRODUCT_CHUNK>(x.try_into().unwrap(), y.try_into().unwrap())
+        })
+        .chain(p.iter().zip(q.iter()).map(|(x, y)| *x * y))
+        .sum()
+}
+
+// Computes the entries of the product of the matrices, whose shapes are compatible and
+// whose second factor has the specified positive width, in the row-major order
+fn tiled_product<F: Field>(
+    a: &(impl Rows<F> + ?Sized),
+    b: &(impl Rows<F> + ?Sized),
+    c: usize,
+) -> Vec<F> {
+    let (h, k) = (a.row_count(), b.row_count());
+    // Storing the columns of the second factor contiguously
+    let mut t = vec![F::ZERO; c * k];
+    for (i, r) in b.rows().enumerate() {
+        for (x, e) in r.iter().enumerate() {
+            t[x * k + i] = *e;
+        }
+    }
+    let mut e = vec![F::ZERO; h * c];
+    for v in (0..h).step_by(PRODUCT_TILE) {
+        for u in (0..c).step_by(PRODUCT_TILE) {
+            for y in v..(v + PRODUCT_TILE).min(h) {
+                let s = a.row(y);
+                for x in u..(u + PRODUCT_TILE).min(c) {
+                    e[y * c + x] = dot_product(s, &t[x * k..(x + 1) * k]);
+                }
+            }
+        }
+    }
+    e
+}
+
+// Computes the order of the square matrix specified by its rows for the trait "LinearMap"
+fn rows_order<F: Field>(a: &(impl Rows<F> + ?Sized)) -> Option<usize> {
+    let n = a.row_count();
+    ((n > 0) && (a.row_length() == Some(n))).then_some(n)
+}
+
+// Computes the product of the matrix specified by its rows
+// and the column vector for the trait "LinearMap"
+fn rows_image<F: Field>(a: &(impl Rows<F> + ?Sized), v: &[F], w: &mut [F]) {
+    for (e, r) in w.iter_mut().zip(a.rows()) {
+        *e = dot_product(r, v);
+    }
+}
+
+// The matrix order, starting from which the products of the Cauchy matrices and column
+// vectors are computed by means of the rational functions and the remainder tree. Both
+// methods have been measured to be equally fast for the orders between 128 and 256, and
+// the remainder tree is 2-3 times faster for the orders between 1024 and 4096
+const CAUCHY_FAST_THRESHOLD: usize = 256;
+
+// The number of the points in the groups, whose subproducts are the leaves of the
+// subproduct tree, so that the values in each group are computed by the Horner method
+const TREE_LEAF_SIZE: usize = 32;
+
+// Computes the levels of the subproduct tree for the nonempty list of the points, where
+// the first level consists of the products of z - y_i for the groups of the specified
+// number of consecutive points, and each next level consists of the products of the pairs
+// of the nodes of the previous level, so that an unpaired node is moved to the next level
+fn subproduct_tree<F: PrimeField>(y: &[F], s: usize) -> Vec<Vec<DensePolynomial<F>>> {
+    let l = y
+        .chunks(s)
+        .map(|c| {
+            c.iter().fold(
+                DensePolynomial::from_coefficients_vec(vec![F::ONE]),
+                |p, e| {
+                    poly::product(
+                        &p,
+                        &DensePolynomial::from_coefficients_vec(vec![-*e, F::ONE]),
+                    )
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut t = vec![l];
+    while t[t.len() - 1].len() > 1 {
+        let l = t[t.len() - 1]
+            .chunks(2)
+            .map(|c| match c {
+                [a, b] => poly::product(a, b),
+                _ => c[0].clone(),
+            })
+            .collect();
+        t.push(l);
+    }
+    t
+}
+
+// Contains the data of the fast product of a Cauchy matrix and vectors, which does not
+// depend on the vectors, so it is computed once for the matrix and reused by the products
+#[derive(Clone, Debug)]
+struct CauchyTree<F: PrimeField> {
+    // The levels of the subproduct tree for the points x, whose leaves are z - x_j
+    q: Vec<Vec<DensePolynomial<F>>>,
+    // The quotient rings modulo the nodes of the subproduct tree for the points y, whose
+    // reciprocals are extended enough for reducing the remainders modulo the parent nodes
+    r: Vec<Vec<ring::ModRing<F>>>,
+    // The values -1 / Q(y_i), where Q(z) is the product of z - x_j
+    d: Vec<F>,
+}
+
+impl<F: PrimeField> CauchyTree<F> {
+    // Computes the data for the specified nonempty lists of the distinct points
+    fn new(x: &[F], y: &[F]) -> Self {
+        let q = subproduct_tree(x, 1);
+        let t = subproduct_tree(y, TREE_LEAF_SIZE);
+        let r = t
+            .iter()
+            .enumerate()
+            .map(|(h, l)| {
+                l.iter()
+                    .enumerate()
+                    .map(|(i, m)| {
+                        // Each node is the product of at least one linear polynomial
+                        let mut g = ring::ModRing::new(m).unwrap();
+                        // The remainders modulo the parent node have smaller degrees than
+                        // it, and the degree of Q is the degree of the root plus 1
+                        g.reserve(match t.get(h + 1) {
+                            Some(u) => u[i / 2].coeffs.len() - m.coeffs.len(),
+                            None => 1,
+                        });
+                        g
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut c = Self { q, r, d: vec![] };
+        let e = c.values(&c.q[c.q.len() - 1][0].coeffs, y);
+        // Since all points are distinct, the values of Q are invertible
+        c.d = crate::batch_inverse(&e)
+            .unwrap()
+            .iter()
+            .map(|e| -*e)
+            .collect();
+        c
+    }
+
+    // Computes the numerator N(z) of the sum of the fractions v_j / (z - x_j), whose
+    // denominator is Q(z), by means of summing the pairs of the fractions level by level
+    fn numerator(&self, v: &[F]) -> DensePolynomial<F> {
+        let mut p = v
+            .iter()
+            .map(|e| DensePolynomial::from_coefficients_vec(vec![*e]))
+            .collect::<Vec<_>>();
+        for l in self.q[..self.q.len() - 1].iter() {
+            p = p
+                .chunks(2)
+                .zip(l.chunks(2))
+                .map(|c| match c {
+                    ([a, c], [b, d]) => &poly::product(a, d) + &poly::product(c, b),
+                    _ => c.0[0].clone(),
+                })
+                .collect();
+        }
+        p.pop().unwrap()
+    }
+
+    // Computes the values of the polynomial with the specified coefficients at the points y
+    // by means of reducing it modulo the nodes from the root to the leaves, so that the
+    // remainders modulo the leaves are evaluated by the Horner method
+    fn values(&self, p: &[F], y: &[F]) -> Vec<F> {
+        let (mut q, mut s) = (Vec::new(), Vec::new());
+        let mut r = vec![p.to_vec()];
+        for l in self.r.iter().rev() {
+            r = l
+                .iter()
+                .enumerate()
+                .map(|(i, m)| {
+                    let mut t = r[i / 2].clone();
+                    m.reduce_into(&mut t, &mut q, &mut s);
+                    t
+                })
+                .collect();
         }
+        y.chunks(TREE_LEAF_SIZE)
+            .zip(r.iter())
+            .flat_map(|(c, p)| {
+                c.iter()
+                    .map(|e| p.iter().rev().fold(F::ZERO, |s, a| s * e + a))
+            })
+            .collect()
     }
-    Some((0..n).map(|y| m[y][n]).collect())
 }
@@ -1,34 +1,144 @@
 //! Provides auxiliary tools for working with numbers.
 
-/// Computes the ascending list of prime numbers that divide
-/// the argument by means of the wheel factorization method.
-pub fn prime_divisors(mut n: u32) -> Vec<u32> {
+use num_bigint::BigUint;
+use num_integer::Integer;
+
+/// Computes the ascending list of the pairs, each of which consists of a prime divisor of
+/// the first argument and its multiplicity, by means of the trial division by the small
+/// numbers and Pollard's rho method with Brent's cycle detection. The second argument
+/// bounds the total number of the iterations of the rho method, which may be infeasible
+/// for the numbers with several large prime divisors. If the first argument is 0 or the
+/// bound has been exceeded, then None is returned.
+pub fn big_factorization(n: &BigUint, mut k: u64) -> Option<Vec<(BigUint, u32)>> {
+    if n == &BigUint::ZERO {
+        return None;
+    }
+    let (mut m, mut f) = (n.clone(), Vec::new());
+    for d in 2..1000u32 {
+        // The composite trial divisors never divide the remaining number
+        // after all smaller prime divisors have been extracted
+        let d = BigUint::from(d);
+        while m.is_multiple_of(&d) {
+            m /= &d;
+            f.push(d.clone());
+        }
+    }
+    let mut c = vec![m];
+    while let Some(m) = c.pop() {
+        if m == BigUint::from(1u32) {
+            continue;
+        }
+        if primality(&m) {
+            f.push(m);
+            continue;
+        }
+        let d = rho_divisor(&m, &mut k)?;
+        c.push(&m / &d);
+        c.push(d);
+    }
+    f.sort();
+    Some(f.into_iter().fold(Vec::new(), |mut r, p| {
+        match r.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => r.push((p, 1)),
+        }
+        r
+    }))
+}
+
+/// Computes the ascending list of the pairs, each of which consists of a divisor d of the
+/// second argument and the value of the d-th cyclotomic polynomial at the first argument.
+/// The product of these values is p^n - 1, where p and n are the first and the second
+/// arguments respectively, so they split this number into smaller factors, which can be
+/// factorised independently. The values are computed by means of dividing p^d - 1 by the
+/// values for the other divisors of d. If the first argument is less than 2 or the second
+/// argument is 0, then None is returned.
+pub fn cyclotomic_values(p: &BigUint, n: u32) -> Option<Vec<(u32, BigUint)>> {
+    if (p < &BigUint::from(2u32)) || (n == 0) {
+        return None;
+    }
+    let mut v = Vec::<(u32, BigUint)>::new();
+    for d in (1..=n).filter(|d| n % *d == 0) {
+        let mut c = p.pow(d) - 1u32;
+        for (e, w) in v.iter() {
+            if d % *e == 0 {
+                c /= w;
+            }
+        }
+        v.push((d, c));
+    }
+    Some(v)
+}
+
+/// Computes the ascending list of the positive divisors of the argument by means of
+/// the function "factorization". If the argument is 0, then the list is empty.
+pub fn divisors(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return vec![];
+    }
+    let mut d = vec![1];
+    for (p, e) in factorization(n) {
+        let k = d.len();
+        let mut q = 1;
+        for _ in 0..e {
+            q *= p;
+            for i in 0..k {
+                d.push(d[i] * q);
+            }
+        }
+    }
+    d.sort_unstable();
+    d
+}
+
+/// Computes the ascending list of the pairs, each of which consists of a prime divisor of
+/// p^n - 1, i.e. the order of the multiplicative group of the field with p^n elements, where
+/// p and n are the first and the second arguments respectively, and its multiplicity. The
+/// number is split by means of the function "cyclotomic_values", and the values are
+/// factorised by means of the function "big_factorization" with the iteration bound
+/// specified by the third argument. If the first argument is less than 2, the second
+/// argument is 0 or the bound has been exceeded, then None is returned.
+pub fn extension_group_factorization(p: &BigUint, n: u32, k: u64) -> Option<Vec<(BigUint, u32)>> {
+    let mut f = Vec::<(BigUint, u32)>::new();
+    for (_, c) in cyclotomic_values(p, n)? {
+        for (q, e) in big_factorization(&c, k)? {
+            match f.iter_mut().find(|(r, _)| *r == q) {
+                Some((_, d)) => *d += e,
+                None => f.push((q, e)),
+            }
+        }
+    }
+    f.sort();
+    Some(f)
+}
+
+/// Computes the ascending list of the pairs, each of which consists of a prime divisor of
+/// the argument and its multiplicity, by means of the wheel factorization method. If the
+/// argument is less than 2, then the list is empty.
+pub fn factorization(mut n: u64) -> Vec<(u64, u32)> {
     if n < 2 {
         return vec![];
     }
-    let mut p = Vec::with_capacity(n.ilog2() as usize);
+    let mut f = Vec::with_capacity(n.ilog2() as usize);
     if n % 2 == 0 {
-        p.push(2);
+        f.push((2, n.trailing_zeros()));
         n >>= n.trailing_zeros();
     }
-    fn extract(f: u32, m: &mut u32, l: &mut Vec<u32>) {
-        l.push(f);
-        *m /= f;
-        while *m % f == 0 {
-            *m /= f;
+    fn extract(d: u64, m: &mut u64, l: &mut Vec<(u64, u32)>) {
+        let mut e = 0;
+        while *m % d == 0 {
+            *m /= d;
+            e += 1;
         }
+        l.push((d, e));
     }
     if n % 3 == 0 {
-        extract(3, &mut n, &mut p);
+        extract(3, &mut n, &mut f);
     }
-    fn sqrt(v: u32) -> u32 {
-        (v as f64).sqrt().round() as u32
-    }
-    let (mut d, mut s, mut b) = (5u32, 2u32, sqrt(n));
-    while d <= b {
+    let (mut d, mut s) = (5u64, 2u64);
+    while d <= n / d {
         if n % d == 0 {
-            extract(d, &mut n, &mut p);
-            b = sqrt(n);
+            extract(d, &mut n, &mut f);
         }
         // At this point, a trial divisor is either 5 or 6k + 1
         // or 6k + 5 for some positive integer k. Therefore, the
@@ -40,9 +150,95 @@ pub fn prime_divisors(mut n: u32) -> Vec<u32> {
         (d, s) = (d + s, s ^ 6);
     }
     if n > 1 {
-        p.push(n)
+        f.push((n, 1))
     }
-    p
+    f
+}
+
+/// Computes the greatest common divisor of the arguments by means of the Euclidean
+/// algorithm. If both arguments are 0, then 0 is returned.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Computes the value of the Moebius function for the argument by means of the function
+/// "factorization", i.e. 0, if the argument is divisible by the square of a prime number,
+/// and (-1)^k otherwise, where k is the number of its prime divisors. If the argument is
+/// 0, then None is returned.
+pub fn moebius(n: u64) -> Option<i8> {
+    if n == 0 {
+        return None;
+    }
+    let f = factorization(n);
+    if f.iter().any(|(_, e)| *e > 1) {
+        Some(0)
+    } else if f.len() % 2 == 0 {
+        Some(1)
+    } else {
+        Some(-1)
+    }
+}
+
+/// Checks whether the argument is a prime number by means of the Miller-Rabin test with
+/// the prime bases less than 40, which is deterministic for the numbers less than 3 * 10^24
+/// and has a negligible probability of an error for the larger ones.
+pub fn primality(n: &BigUint) -> bool {
+    const B: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < &BigUint::from(2u32) {
+        return false;
+    }
+    for b in B {
+        if n == &BigUint::from(b) {
+            return true;
+        }
+        if n.is_multiple_of(&BigUint::from(b)) {
+            return false;
+        }
+    }
+    // Representing n - 1 as d * 2^s, where d is odd
+    let m = n - 1u32;
+    let s = m.trailing_zeros().unwrap_or(0);
+    let d = &m >> s;
+    'b: for b in B {
+        let mut x = BigUint::from(b).modpow(&d, n);
+        if (x == BigUint::from(1u32)) || (x == m) {
+            continue;
+        }
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == m {
+                continue 'b;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Computes the ascending list of prime numbers that divide
+/// the argument by means of the function "factorization".
+pub fn prime_divisors(n: u32) -> Vec<u32> {
+    factorization(n as u64)
+        .into_iter()
+        .map(|(p, _)| p as u32)
+        .collect()
+}
+
+/// Computes the remainder of the division of the first argument by the second one. The
+/// first argument is an unsigned integer represented by its 64-bit chunks stored in the
+/// little-endian order. If the second argument is 0, then None is returned.
+pub fn residue(n: &[u64], m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    Some(
+        n.iter()
+            .rev()
+            .fold(0u128, |r, c| ((r << 64) | *c as u128) % m as u128) as u64,
+    )
 }
 
 /// Creates an iterator over the bits of the argument's big-endian
@@ -59,3 +255,59 @@ pub fn reversed_bits(n: &[u64]) -> impl Iterator<Item = bool> + '_ {
         .flat_map(|c| (0..64).map(move |b| c & (1 << b) != 0))
         .skip_while(|b| !b)
 }
+
+/// Computes the value of Euler's totient function for the argument, i.e. the number of
+/// the positive integers, which do not exceed the argument and are coprime to it, by
+/// means of the function "factorization". If the argument is 0, then 0 is returned.
+pub fn totient(n: u64) -> u64 {
+    factorization(n)
+        .into_iter()
+        .fold(n, |r, (p, _)| r / p * (p - 1))
+}
+
+// Finds a nontrivial divisor of the odd composite argument by means of Pollard's rho method
+// with Brent's cycle detection, decreasing the remaining number of the iterations
+fn rho_divisor(n: &BigUint, k: &mut u64) -> Option<BigUint> {
+    let o = BigUint::from(1u32);
+    for c in 1u32.. {
+        let f = |x: &BigUint| (x * x + c) % n;
+        let (mut y, mut r, mut q, mut g) = (BigUint::from(2u32), 1u64, o.clone(), o.clone());
+        let (mut x, mut z) = (y.clone(), y.clone());
+        while g == o {
+            x = y.clone();
+            for _ in 0..r {
+                y = f(&y);
+            }
+            let mut j = 0;
+            while (j < r) && (g == o) {
+                // Accumulating the products of the differences in batches
+                // in order to compute the greatest common divisor rarely
+                z = y.clone();
+                for _ in 0..128.min(r - j) {
+                    *k = k.checked_sub(1)?;
+                    y = f(&y);
+                    q = q * if x > y { &x - &y } else { &y - &x } % n;
+                }
+                g = q.gcd(n);
+                j += 128;
+            }
+            r *= 2;
+        }
+        if &g == n {
+            // Some batch has contained the divisor, so
+            // the differences are examined individually
+            loop {
+                *k = k.checked_sub(1)?;
+                z = f(&z);
+                g = if x > z { &x - &z } else { &z - &x }.gcd(n);
+                if g != o {
+                    break;
+                }
+            }
+        }
+        if &g != n {
+            return Some(g);
+        }
+    }
+    None
+}
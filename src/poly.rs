@@ -3,23 +3,433 @@
 use ark_ff::{PrimeField, Zero};
 use ark_poly::{
     polynomial::univariate::{DenseOrSparsePolynomial, DensePolynomial},
-    DenseUVPolynomial, Polynomial,
+    DenseUVPolynomial, EvaluationDomain, Polynomial, Radix2EvaluationDomain,
 };
+use rand::Rng;
 
-/// Checks whether the arguments are coprime polynomials by means of the Euclidean method.
+use crate::{mat::Rows, ring::ModRing};
+
+/// Checks whether the arguments are coprime polynomials by means of the function "gcd".
 pub fn coprimality<F: PrimeField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> bool {
-    let mut a = match reduced_modulo(a, b) {
-        Some(r) => r,
-        None => return !a.is_zero() && (a.degree() == 0),
-    };
-    let mut b = match reduced_modulo(b, &a) {
-        Some(r) => r,
-        None => return b.degree() == 0,
-    };
-    while let Some(r) = reduced_modulo(&a, &b) {
+    let g = gcd(a, b);
+    !g.is_zero() && (g.degree() == 0)
+}
+
+// Checks whether the polynomials with the specified coefficients are coprime by means of
+// the classical Euclidean method, which overwrites the arguments with the remainders, so
+// no values are allocated, and it is faster than the function "gcd" for the small degrees
+pub(crate) fn coprimality_with<F: PrimeField>(a: &mut Vec<F>, b: &mut Vec<F>) -> bool {
+    while b.last().is_some_and(|e| e.is_zero()) {
+        b.pop();
+    }
+    while !b.is_empty() {
+        reduce_in_place(a, b);
+        std::mem::swap(a, b);
+    }
+    a.len() == 1
+}
+
+// Reduces the polynomial with the coefficients specified by the first argument modulo the
+// nonzero one with the coefficients specified by the second argument, whose last coefficient
+// is not 0, in place by means of the schoolbook division, and removes the trailing zeros
+pub(crate) fn reduce_in_place<F: PrimeField>(a: &mut Vec<F>, b: &[F]) {
+    let n = b.len() - 1;
+    // The leading coefficient is not 0, so it is invertible
+    let l = b[n].inverse().unwrap();
+    while a.len() > n {
+        let (c, k) = (a[a.len() - 1] * l, a.len() - 1 - n);
+        for (x, y) in a[k..].iter_mut().zip(b.iter()) {
+            *x -= c * y;
+        }
+        a.pop();
+    }
+    while a.last().is_some_and(|e| e.is_zero()) {
+        a.pop();
+    }
+}
+
+/// Computes the list of the pairs, each of which consists of the product of all monic
+/// irreducible factors of some degree of the specified square-free polynomial and this
+/// degree, by means of the distinct-degree factorisation method. The pairs are sorted by
+/// the degree in the ascending order. If the argument is 0, then None is returned.
+pub fn distinct_degree_factors<F: PrimeField>(
+    c: &DensePolynomial<F>,
+) -> Option<Vec<(DensePolynomial<F>, usize)>> {
+    if c.is_zero() {
+        return None;
+    }
+    let x = new(&[1, 0]);
+    let (mut g, mut h, mut f) = (gcd(c, c), x.clone(), Vec::new());
+    let mut d = 1;
+    while g.degree() >= 2 * d {
+        // At this point, h is x^(p^(d - 1)) modulo g, and the
+        // irreducible factors of g have degrees no less than d
+        h = power_modulo(&h, F::characteristic(), &g)?;
+        let t = gcd(&g, &(&h - &x));
+        if t.degree() > 0 {
+            g = quotient(&g, &t)?;
+            h = reduced_modulo(&h, &g)?;
+            f.push((t, d));
+        }
+        d += 1;
+    }
+    if g.degree() > 0 {
+        // The remaining polynomial is irreducible
+        let d = g.degree();
+        f.push((g, d));
+    }
+    Some(f)
+}
+
+/// Computes the formal derivative of the argument.
+pub fn derivative<F: PrimeField>(p: &DensePolynomial<F>) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_vec(
+        p.coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| F::from(i as u64) * c)
+            .collect(),
+    )
+}
+
+/// Computes the discriminant of the argument by means of its resultant with the
+/// formal derivative of the argument. The discriminant is 0 if and only if the
+/// argument has a repeated root in the algebraic closure of the field. If the degree
+/// of the argument is 0, then None is returned.
+pub fn discriminant<F: PrimeField>(p: &DensePolynomial<F>) -> Option<F> {
+    discriminant_with(&p.coeffs, &mut Vec::new(), &mut Vec::new())
+}
+
+// Computes the same value as the function "discriminant" for the polynomial with the
+// coefficients specified by the first argument, whose last coefficient is not 0, by means
+// of the other arguments as the scratch buffers. If the argument is a constant, then None
+// is returned
+pub(crate) fn discriminant_with<F: PrimeField>(
+    p: &[F],
+    u: &mut Vec<F>,
+    v: &mut Vec<F>,
+) -> Option<F> {
+    let n = p.len().checked_sub(1).filter(|n| *n > 0)?;
+    u.clear();
+    u.extend_from_slice(p);
+    // Computing the formal derivative
+    v.clear();
+    v.extend(
+        p.iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| F::from(i as u64) * c),
+    );
+    let r = resultant_with(u, v) / p[n];
+    // The sign is determined by the parity of n(n - 1) / 2
+    Some(if n % 4 < 2 { r } else { -r })
+}
+
+/// Computes the triple of the monic greatest common divisor g of the arguments a and b and
+/// the Bezout cofactors s and t, such that s * a + t * b = g, by means of the Euclidean
+/// method accelerated with the half-GCD method. The half-GCD method computes the product of
+/// the matrices of the Euclidean steps, which reduce the degree of the first argument by
+/// half, from the higher halves of the coefficients recursively, and the classical
+/// Euclidean method is used for the degrees below the threshold, where it is faster. If
+/// both arguments are 0, then g and t are 0, and s is 1.
+pub fn extended_gcd<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>) {
+    let mut t = identity_transform();
+    let g = euclidean_reduction(a, b, Some(&mut t));
+    let [[u, v], _] = t;
+    if g.is_zero() {
+        return (g, u, v);
+    }
+    // The leading coefficient of a nonzero polynomial is invertible
+    let l = g.coeffs[g.degree()].inverse().unwrap();
+    (&g * l, &u * l, &v * l)
+}
+
+// The degree, below which the classical Euclidean method is used in the half-GCD method
+const HALF_GCD_THRESHOLD: usize = 32;
+
+// Represents the linear map (a, b) -> (m[0][0] * a + m[0][1] * b, m[1][0] * a + m[1][1] * b)
+type Transform<F> = [[DensePolynomial<F>; 2]; 2];
+
+// Computes the last nonzero remainder of the Euclidean method for the arguments, which is
+// not normalised, and composes the map from the arguments to it and the next remainder 0
+// with the specified map, if any
+fn euclidean_reduction<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+    mut t: Option<&mut Transform<F>>,
+) -> DensePolynomial<F> {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while !b.is_zero() {
+        if (b.degree() >= HALF_GCD_THRESHOLD) && (a.degree() > b.degree()) {
+            let m = half_gcd(&a, &b);
+            (a, b) = transform_application(&m, &a, &b);
+            if let Some(t) = t.as_deref_mut() {
+                *t = transform_composition(&m, t);
+            }
+            if b.is_zero() {
+                break;
+            }
+        }
+        let (q, r) = division(&a, &b);
         (a, b) = (b, r);
+        if let Some(t) = t.as_deref_mut() {
+            *t = transform_composition(&step_transform(q), t);
+        }
     }
-    a.degree() == 0
+    a
+}
+
+// Computes the map, which is the composition of the Euclidean steps transforming the
+// arguments a and b, where the degree of a exceeds the degree of b, into the consecutive
+// remainders, such that the degree of the first one is no less than the half of the
+// degree of a and the degree of the second one is less than it. Since the quotients
+// depend only on the higher coefficients, the map is computed recursively from the
+// higher halves of the arguments
+fn half_gcd<F: PrimeField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> Transform<F> {
+    let m = a.degree().div_ceil(2);
+    if b.is_zero() || (b.degree() < m) {
+        return identity_transform();
+    }
+    if a.degree() < HALF_GCD_THRESHOLD {
+        let (mut c, mut d, mut r) = (a.clone(), b.clone(), identity_transform());
+        while !d.is_zero() && (d.degree() >= m) {
+            let (q, e) = division(&c, &d);
+            (c, d) = (d, e);
+            r = transform_composition(&step_transform(q), &r);
+        }
+        return r;
+    }
+    let r = half_gcd(&shifted(a, m), &shifted(b, m));
+    let (c, d) = transform_application(&r, a, b);
+    if d.is_zero() || (d.degree() < m) {
+        return r;
+    }
+    let (q, e) = division(&c, &d);
+    let r = transform_composition(&step_transform(q), &r);
+    if e.is_zero() || (e.degree() < m) {
+        return r;
+    }
+    let k = 2 * m - d.degree();
+    transform_composition(&half_gcd(&shifted(&d, k), &shifted(&e, k)), &r)
+}
+
+// Computes the quotient and the remainder of the division by the nonzero polynomial
+fn division<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    DenseOrSparsePolynomial::from(a)
+        .divide_with_q_and_r(&DenseOrSparsePolynomial::from(b))
+        .unwrap()
+}
+
+// Computes the argument divided by x^k with the remainder discarded
+fn shifted<F: PrimeField>(a: &DensePolynomial<F>, k: usize) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_slice(&a.coeffs[k.min(a.coeffs.len())..])
+}
+
+fn identity_transform<F: PrimeField>() -> Transform<F> {
+    [
+        [new(&[1]), DensePolynomial::zero()],
+        [DensePolynomial::zero(), new(&[1])],
+    ]
+}
+
+// Creates the map of the Euclidean step (a, b) -> (b, a - q * b)
+fn step_transform<F: PrimeField>(q: DensePolynomial<F>) -> Transform<F> {
+    [[DensePolynomial::zero(), new(&[1])], [new(&[1]), -q]]
+}
+
+// Computes the composition of the maps, where the second one is applied first
+fn transform_composition<F: PrimeField>(s: &Transform<F>, r: &Transform<F>) -> Transform<F> {
+    let e = |y: usize, x: usize| &product(&s[y][0], &r[0][x]) + &product(&s[y][1], &r[1][x]);
+    [[e(0, 0), e(0, 1)], [e(1, 0), e(1, 1)]]
+}
+
+fn transform_application<F: PrimeField>(
+    m: &Transform<F>,
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    (
+        &product(&m[0][0], a) + &product(&m[0][1], b),
+        &product(&m[1][0], a) + &product(&m[1][1], b),
+    )
+}
+
+/// Computes the list of the pairs, each of which consists of a monic irreducible factor
+/// of the first argument and its multiplicity, by means of the square-free factorisation,
+/// the function "distinct_degree_factors" and the Cantor-Zassenhaus equal-degree splitting,
+/// which uses the source of randomness specified by the second argument. The pairs are
+/// sorted by the degree of the factor and then by its coefficients. If the first argument
+/// is 0, then None is returned.
+pub fn factors<F: PrimeField>(
+    c: &DensePolynomial<F>,
+    r: &mut (impl Rng + ?Sized),
+) -> Option<Vec<(DensePolynomial<F>, u32)>> {
+    if c.is_zero() {
+        return None;
+    }
+    let mut f = Vec::new();
+    for (s, m) in square_free_factors(c) {
+        for (g, d) in distinct_degree_factors(&s)? {
+            for h in equal_degree_factors(&g, d, r)? {
+                f.push((h, m));
+            }
+        }
+    }
+    f.sort_by(|(a, _), (b, _)| (a.degree(), &a.coeffs).cmp(&(b.degree(), &b.coeffs)));
+    Some(f)
+}
+
+// Splits the monic product of distinct irreducible polynomials of degree d into them
+fn equal_degree_factors<F: PrimeField>(
+    g: &DensePolynomial<F>,
+    d: usize,
+    r: &mut (impl Rng + ?Sized),
+) -> Option<Vec<DensePolynomial<F>>> {
+    let (mut f, mut s) = (Vec::with_capacity(g.degree() / d), vec![g.clone()]);
+    let b = F::MODULUS == F::BigInt::from(2u64);
+    while let Some(g) = s.pop() {
+        if g.degree() <= d {
+            f.push(g);
+            continue;
+        }
+        let h = loop {
+            let a = DensePolynomial::from_coefficients_vec(
+                (0..g.degree()).map(|_| F::rand(r)).collect(),
+            );
+            // Computing the sum of the conjugates of a, if the characteristic is 2, and
+            // the product of them otherwise, so that the result u belongs to the base
+            // field modulo each irreducible factor. In the latter case, about a half of
+            // the factors divide u^((p - 1) / 2) - 1, so the splitting is found quickly
+            let (mut t, mut u) = (a.clone(), a);
+            for _ in 1..d {
+                t = power_modulo(&t, F::characteristic(), &g)?;
+                u = if b {
+                    &u + &t
+                } else {
+                    reduced_modulo(&product(&u, &t), &g)?
+                };
+            }
+            if !b {
+                u = &power_modulo(&u, F::MODULUS_MINUS_ONE_DIV_TWO.as_ref(), &g)? - &new(&[1]);
+            }
+            let h = gcd(&u, &g);
+            if (h.degree() > 0) && (h.degree() < g.degree()) {
+                break h;
+            }
+        };
+        s.push(quotient(&g, &h)?);
+        s.push(h);
+    }
+    Some(f)
+}
+
+/// Computes the p-th power of the first argument modulo the polynomial, for which the
+/// Frobenius matrix specified by the second argument has been computed by means of the
+/// function "frobenius_matrix", where p is the field characteristic. Since the Frobenius
+/// endomorphism fixes the coefficients, this power is obtained as the product of the matrix
+/// and the coefficient vector of the first argument. If the second argument is not a square
+/// matrix or the degree of the first argument is not less than the matrix order, then None
+/// is returned.
+pub fn frobenius_image<F: PrimeField>(
+    g: &DensePolynomial<F>,
+    m: &(impl Rows<F> + ?Sized),
+) -> Option<DensePolynomial<F>> {
+    let n = m.row_count();
+    if (m.row_length() != Some(n)) || (g.coeffs.len() > n) {
+        return None;
+    }
+    let mut v = g.coeffs.clone();
+    v.resize(n, F::ZERO);
+    Some(DensePolynomial::from_coefficients_vec(
+        crate::mat::product_vector(m, &v)?,
+    ))
+}
+
+/// Computes the matrix of the Frobenius endomorphism of the quotient ring modulo the
+/// argument, whose j-th column is the coefficient vector of x^(j * p) modulo the argument,
+/// where p is the field characteristic. The polynomial x^p modulo the argument is computed
+/// once by means of the type "ring::ModRing", and the further Frobenius images are
+/// obtained by means of the function "frobenius_image" at the cost of a matrix-vector
+/// product, which is the modular composition with x^p. If the degree of the argument is 0,
+/// then None is returned.
+pub fn frobenius_matrix<F: PrimeField>(c: &DensePolynomial<F>) -> Option<Vec<Vec<F>>> {
+    if c.is_zero() || (c.degree() == 0) {
+        return None;
+    }
+    let n = c.degree();
+    let mut m = Vec::new();
+    frobenius_matrix_with(
+        &mut ModRing::new(c)?,
+        &mut m,
+        &mut Vec::new(),
+        &mut Vec::new(),
+    );
+    Some(m.chunks(n).map(|r| r.to_vec()).collect())
+}
+
+// Computes the same matrix as the function "frobenius_matrix" for the modulus of the ring
+// specified by the first argument in the second argument, whose entries are stored in the
+// row-major order, by means of the other arguments as the scratch buffers
+pub(crate) fn frobenius_matrix_with<F: PrimeField>(
+    r: &mut ModRing<F>,
+    m: &mut Vec<F>,
+    h: &mut Vec<F>,
+    v: &mut Vec<F>,
+) {
+    let n = r.modulus().degree();
+    r.x_power_into(F::characteristic(), h);
+    m.clear();
+    m.resize(n * n, F::ZERO);
+    // The modulus is not a constant, so 1 is reduced
+    v.clear();
+    v.push(F::ONE);
+    for j in 0..n {
+        for (i, e) in v.iter().enumerate() {
+            m[i * n + j] = *e;
+        }
+        r.product_assign(v, h);
+    }
+}
+
+/// Computes the monic greatest common divisor of the arguments by means of the Euclidean
+/// method accelerated with the half-GCD method, as in the function "extended_gcd". If
+/// both arguments are 0, then 0 is returned.
+pub fn gcd<F: PrimeField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let a = euclidean_reduction(a, b, None);
+    if a.is_zero() {
+        return a;
+    }
+    // The leading coefficient of a nonzero polynomial is invertible
+    let l = a.coeffs[a.degree()].inverse().unwrap();
+    &a * l
+}
+
+/// Checks whether the argument is an irreducible polynomial by means of Algorithm 2.2.9
+/// in the book "Prime Numbers - A Computational Perspective (2nd edn.)" by R. Crandall
+/// and C. Pomerance. Polynomials of degree 0 are not considered irreducible.
+pub fn irreducibility<F: PrimeField>(c: &DensePolynomial<F>) -> bool {
+    if c.degree() == 0 {
+        return false;
+    }
+    let x = new(&[1, 0]);
+    let mut r = x.clone();
+    for _ in 1..=c.degree() / 2 {
+        r = match power_modulo(&r, F::characteristic(), c) {
+            Some(r) => r,
+            None => return false,
+        };
+        if !coprimality(&(&r - &x), c) {
+            return false;
+        }
+    }
+    true
 }
 
 /// Creates a polynomial from the specified big-endian slice of coefficients.
@@ -28,24 +438,246 @@ pub fn new<F: PrimeField>(c: &[impl Into<F> + Clone]) -> DensePolynomial<F> {
 }
 
 /// Computes the first argument raised to the power of the second argument
-/// modulo the third one by means of the left-to-right binary exponentiation
-/// method. The second argument is an unsigned integer represented by its
-/// 64-bit chunks stored in the little-endian order. If the modulus is 0,
-/// then None is returned.
+/// modulo the third one by means of the function "ModRing::power". The
+/// second argument is an unsigned integer represented by its 64-bit
+/// chunks stored in the little-endian order. If the modulus is 0, then
+/// None is returned.
 pub fn power_modulo<F: PrimeField>(
     p: &DensePolynomial<F>,
     e: &[u64],
     m: &DensePolynomial<F>,
 ) -> Option<DensePolynomial<F>> {
-    let p = reduced_modulo(p, m)?;
-    let mut r = new(&[(m.degree() > 0) as u64]);
-    for b in crate::num::reversed_bits(e) {
-        r = reduced_modulo(&(&r * &r), m)?;
-        if b {
-            r = reduced_modulo(&(&r * &p), m)?;
+    match ModRing::new(m) {
+        Some(mut r) => Some(r.power(p, e)),
+        // The modulus is a nonzero constant, so each polynomial is congruent to 0
+        None => reduced_modulo(p, m),
+    }
+}
+
+/// Computes the characteristic polynomial of the i-th power of a matrix from the
+/// characteristic polynomial of this matrix, which is specified by the first argument,
+/// where i is the second argument. The result is the monic polynomial, whose roots are
+/// the i-th powers of the roots of the first argument, and it is obtained from the traces
+/// of the powers of x^i in the quotient ring modulo the first argument by means of the
+/// Newton identities. If the degree of the first argument is 0 or is not less than the
+/// field characteristic, then None is returned.
+pub fn power_polynomial<F: PrimeField>(
+    c: &DensePolynomial<F>,
+    i: u32,
+) -> Option<DensePolynomial<F>> {
+    let n = c.degree();
+    if (n == 0) || (F::MODULUS <= F::BigInt::from(n as u64)) {
+        // The first argument is a constant, or the Newton
+        // identities cannot be solved for the coefficients
+        return None;
+    }
+    let s = power_sums(c, n)?;
+    let g = power_modulo(&new(&[1, 0]), &[i as u64], c)?;
+    // Computing the power sums of the roots of the sought polynomial,
+    // i.e. the traces of the powers of x^i in the quotient ring
+    let (mut h, mut t) = (new(&[1]), Vec::with_capacity(n + 1));
+    t.push(F::from(n as u64));
+    for _ in 1..=n {
+        h = reduced_modulo(&product(&h, &g), c)?;
+        t.push(h.coeffs.iter().zip(s.iter()).map(|(x, y)| *x * y).sum());
+    }
+    // Recovering the coefficients from the power sums. The division
+    // is possible, since the characteristic exceeds the degree
+    let mut b = vec![F::ZERO; n + 1];
+    b[n] = F::ONE;
+    for k in 1..=n {
+        let v: F = (1..k).map(|j| b[n - j] * t[k - j]).sum::<F>() + t[k];
+        b[n - k] = -v / F::from(k as u64);
+    }
+    Some(DensePolynomial::from_coefficients_vec(b))
+}
+
+/// Computes the sums of the j-th powers of the roots of the first argument for
+/// all j from 0 to the second argument exclusively by means of the Newton identities.
+/// If the degree of the first argument is 0, then None is returned.
+pub fn power_sums<F: PrimeField>(c: &DensePolynomial<F>, k: usize) -> Option<Vec<F>> {
+    let n = c.degree();
+    if n == 0 {
+        // The first argument is a constant
+        return None;
+    }
+    let a = &c.coeffs;
+    let l = a[n].inverse()?;
+    let mut s = Vec::with_capacity(k);
+    for j in 0..k {
+        if j == 0 {
+            s.push(F::from(n as u64));
+            continue;
+        }
+        let mut v: F = (1..j.min(n + 1)).map(|i| a[n - i] * s[j - i]).sum();
+        if j <= n {
+            // The Newton identities for the first powers
+            // contain the term with the power index factor
+            v += a[n - j] * F::from(j as u64);
+        }
+        s.push(-v * l);
+    }
+    Some(s)
+}
+
+/// Computes the ascending list of the distinct roots of the first argument, which
+/// belong to the field, by means of extracting the product of the linear factors as
+/// the greatest common divisor with x^p - x, where p is the field characteristic, and
+/// splitting it by means of the Cantor-Zassenhaus method, which uses the source of
+/// randomness specified by the second argument. If the first argument is 0, then
+/// None is returned.
+pub fn roots<F: PrimeField>(c: &DensePolynomial<F>, r: &mut (impl Rng + ?Sized)) -> Option<Vec<F>> {
+    if c.is_zero() {
+        return None;
+    }
+    let x = new(&[1, 0]);
+    let g = gcd(&(&power_modulo(&x, F::characteristic(), c)? - &x), c);
+    let mut v = Vec::with_capacity(g.degree());
+    let mut s = vec![g];
+    while let Some(g) = s.pop() {
+        match g.degree() {
+            0 => {}
+            // The polynomial is monic, so its root is the negated constant term
+            1 => v.push(-g.coeffs[0]),
+            _ if F::MODULUS == F::BigInt::from(2u64) => {
+                // The polynomial equals x^2 - x, which cannot be split by the method
+                v.extend([F::ZERO, F::ONE]);
+            }
+            _ => {
+                // The polynomial is a product of distinct linear factors, and for a
+                // random shift a about half of its roots t satisfy the equality
+                // (t + a)^((p - 1) / 2) = 1, so the splitting is found quickly
+                let h = loop {
+                    let y = new(&[F::ONE, F::rand(r)]);
+                    let e = power_modulo(&y, F::MODULUS_MINUS_ONE_DIV_TWO.as_ref(), &g)?;
+                    let h = gcd(&(&e - &new(&[1])), &g);
+                    if (h.degree() > 0) && (h.degree() < g.degree()) {
+                        break h;
+                    }
+                };
+                s.push(quotient(&g, &h)?);
+                s.push(h);
+            }
+        }
+    }
+    v.sort();
+    Some(v)
+}
+
+/// Computes the product of the arguments. The method is chosen by the degrees: the
+/// schoolbook method is used for the small ones, the number theoretic transform is used
+/// for the large ones, if the field has a large enough multiplicative subgroup, whose
+/// order is a power of 2, and the Karatsuba method is used otherwise. Unlike the
+/// multiplication provided by ark-poly, which always uses the fast Fourier transform and
+/// panics, if there is no such subgroup, this function is applicable to the polynomials
+/// over any prime field.
+pub fn product<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> DensePolynomial<F> {
+    if a.is_zero() || b.is_zero() {
+        return DensePolynomial::zero();
+    }
+    DensePolynomial::from_coefficients_vec(convolution(&a.coeffs, &b.coeffs))
+}
+
+// The length of the shorter factor, below which the schoolbook method is used
+const KARATSUBA_THRESHOLD: usize = 12;
+
+// The length of the product, starting from which the number theoretic transform is used
+const NTT_THRESHOLD: usize = 128;
+
+// Computes the coefficients of the product of the polynomials, whose nonempty lists of the
+// coefficients are specified, by means of the method chosen as in the function "product"
+pub(crate) fn convolution<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut c = Vec::new();
+    convolution_into(a, b, &mut c);
+    c
+}
+
+// Computes the same value as the function "convolution" in the third argument, whose
+// capacity is reused, so the schoolbook method does not allocate, and the Karatsuba
+// method and the number theoretic transform allocate only the temporary values
+pub(crate) fn convolution_into<F: PrimeField>(a: &[F], b: &[F], c: &mut Vec<F>) {
+    let l = a.len() + b.len() - 1;
+    c.clear();
+    if (a.len().min(b.len()) >= KARATSUBA_THRESHOLD) && (l >= NTT_THRESHOLD) {
+        if let Some(d) = Radix2EvaluationDomain::<F>::new(l) {
+            c.extend_from_slice(a);
+            d.fft_in_place(c);
+            let v = d.fft(b);
+            c.iter_mut().zip(v.iter()).for_each(|(x, y)| *x *= y);
+            d.ifft_in_place(c);
+            c.truncate(l);
+            return;
+        }
+    }
+    c.resize(l, F::ZERO);
+    karatsuba_convolution(a, b, c);
+}
+
+// Adds the product of the first two arguments to the third one, whose length is at least
+// the length of the product, by means of the Karatsuba method, which splits the factors
+// at the middle of the longer one, and the schoolbook method
+fn karatsuba_convolution<F: PrimeField>(a: &[F], b: &[F], c: &mut [F]) {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                c[i + j] += *x * y;
+            }
         }
+        return;
+    }
+    let h = a.len().max(b.len()) / 2;
+    let ((p, q), (u, v)) = (a.split_at(h.min(a.len())), b.split_at(h.min(b.len())));
+    if q.is_empty() || v.is_empty() {
+        // The shorter factor is not split, so the longer one is multiplied by it by halves
+        let (s, (p, q)) = if q.is_empty() {
+            (a, b.split_at(h))
+        } else {
+            (b, a.split_at(h))
+        };
+        karatsuba_convolution(s, p, c);
+        karatsuba_convolution(s, q, &mut c[h..]);
+        return;
+    }
+    // The product is z0 + z1 * x^h + z2 * x^(2h), where z1 = (p + q)(u + v) - z0 - z2
+    let mut z0 = vec![F::ZERO; p.len() + u.len() - 1];
+    let mut z2 = vec![F::ZERO; q.len() + v.len() - 1];
+    karatsuba_convolution(p, u, &mut z0);
+    karatsuba_convolution(q, v, &mut z2);
+    let sum = |p: &[F], q: &[F]| {
+        let mut s = p.to_vec();
+        s.resize(p.len().max(q.len()), F::ZERO);
+        s.iter_mut().zip(q.iter()).for_each(|(x, y)| *x += y);
+        s
+    };
+    karatsuba_convolution(&sum(p, q), &sum(u, v), &mut c[h..]);
+    for (i, x) in z0.into_iter().enumerate() {
+        c[i] += x;
+        c[h + i] -= x;
+    }
+    for (i, x) in z2.into_iter().enumerate() {
+        c[2 * h + i] += x;
+        c[h + i] -= x;
+    }
+}
+
+/// Computes the quotient of the first argument divided by the second.
+/// If the divisor is 0, then None is returned.
+pub fn quotient<F: PrimeField>(
+    p: &DensePolynomial<F>,
+    m: &DensePolynomial<F>,
+) -> Option<DensePolynomial<F>> {
+    // This check is performed for the same reason as in "reduced_modulo"
+    if m.is_zero() {
+        return None;
     }
-    Some(r)
+    Some(
+        DenseOrSparsePolynomial::from(p)
+            .divide_with_q_and_r(&DenseOrSparsePolynomial::from(m))?
+            .0,
+    )
 }
 
 /// Computes the first argument modulo the second.
@@ -65,3 +697,113 @@ pub fn reduced_modulo<F: PrimeField>(
             .1,
     )
 }
+
+/// Computes the resultant of the arguments by means of the Euclidean method. If some
+/// argument is 0, then 0 is returned, and if both arguments are nonzero constants, then
+/// 1 is returned.
+pub fn resultant<F: PrimeField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> F {
+    resultant_with(&mut a.coeffs.clone(), &mut b.coeffs.clone())
+}
+
+// Computes the same value as the function "resultant" for the polynomials with the
+// specified coefficients, which are overwritten with the remainders of the Euclidean method
+pub(crate) fn resultant_with<F: PrimeField>(a: &mut Vec<F>, b: &mut Vec<F>) -> F {
+    for c in [&mut *a, &mut *b] {
+        while c.last().is_some_and(|e| e.is_zero()) {
+            c.pop();
+        }
+    }
+    if a.is_empty() || b.is_empty() {
+        return F::ZERO;
+    }
+    let mut r = F::ONE;
+    loop {
+        let (m, n) = (a.len() - 1, b.len() - 1);
+        if n == 0 {
+            return r * b[0].pow([m as u64]);
+        }
+        if m == 0 {
+            return r * a[0].pow([n as u64]);
+        }
+        // Replacing the first argument with its remainder modulo the second one
+        reduce_in_place(a, b);
+        if a.is_empty() {
+            // The arguments have a nontrivial common divisor
+            return F::ZERO;
+        }
+        // Using the equality Res(a, b) = (-1)^(mn) * lc(b)^(m - k) * Res(b, c),
+        // where c is the remainder of a modulo b and k is the degree of c
+        if m * n % 2 == 1 {
+            r = -r;
+        }
+        r *= b[n].pow([(m + 1 - a.len()) as u64]);
+        std::mem::swap(a, b);
+    }
+}
+
+// Computes the pairs of the monic square-free factors and their multiplicities
+fn square_free_factors<F: PrimeField>(c: &DensePolynomial<F>) -> Vec<(DensePolynomial<F>, u32)> {
+    let mut f = Vec::new();
+    // Making the polynomial monic by means of computing its greatest common divisor with itself
+    let (mut c, mut k) = (gcd(c, c), 1);
+    while c.degree() > 0 {
+        let d = derivative(&c);
+        if d.is_zero() {
+            // The polynomial is the p-th power of the polynomial consisting of each p-th
+            // coefficient, since the Frobenius endomorphism is trivial on the prime field.
+            // The characteristic does not exceed the degree, so it fits into 64 bits
+            let p = F::characteristic()[0] as usize;
+            c = DensePolynomial::from_coefficients_vec(
+                c.coeffs.iter().step_by(p).copied().collect(),
+            );
+            k *= p as u32;
+            continue;
+        }
+        let mut u = gcd(&c, &d);
+        // The quotient is the product of the distinct irreducible
+        // factors of c, whose multiplicities are not divisible by p
+        let mut w = quotient(&c, &u).unwrap();
+        let mut i = 1;
+        while w.degree() > 0 {
+            let y = gcd(&w, &u);
+            let z = quotient(&w, &y).unwrap();
+            if z.degree() > 0 {
+                f.push((z, i * k));
+            }
+            (w, u, i) = (y.clone(), quotient(&u, &y).unwrap(), i + 1);
+        }
+        // The remaining part consists of the factors, whose multiplicities
+        // are divisible by p, so its derivative is 0
+        c = u;
+    }
+    f
+}
+
+/// Checks whether the argument may be irreducible according to Stickelberger's theorem,
+/// which implies that the discriminant of an irreducible polynomial of degree n over a
+/// prime field of odd characteristic is a quadratic residue if and only if n is odd.
+/// Polynomials of degree 0 and polynomials with repeated roots are rejected. If the field
+/// characteristic is 2, then the theorem is not applicable and true is returned for any
+/// square-free polynomial of positive degree.
+pub fn stickelberger_admissibility<F: PrimeField>(c: &DensePolynomial<F>) -> bool {
+    stickelberger_admissibility_with(&c.coeffs, &mut Vec::new(), &mut Vec::new())
+}
+
+// Computes the same value as the function "stickelberger_admissibility" for the polynomial
+// with the coefficients specified by the first argument, whose last coefficient is not 0,
+// by means of the other arguments as the scratch buffers
+pub(crate) fn stickelberger_admissibility_with<F: PrimeField>(
+    c: &[F],
+    u: &mut Vec<F>,
+    v: &mut Vec<F>,
+) -> bool {
+    let d = match discriminant_with(c, u, v) {
+        Some(d) if !d.is_zero() => d,
+        // The argument is a constant or has a repeated root
+        _ => return false,
+    };
+    if F::MODULUS == F::BigInt::from(2u64) {
+        return true;
+    }
+    d.legendre().is_qr() == ((c.len() - 1) % 2 == 1)
+}
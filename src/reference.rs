@@ -0,0 +1,105 @@
+//! Provides the slow reference implementation of the unconditional P-SPN security level
+//! check, which applies the definition literally and is intended for cross-validating
+//! the MDSECheck method. The minimal polynomials of the matrix powers are computed
+//! directly, and their irreducibility is checked by means of the factorisation.
+
+use ark_ff::{Field, PrimeField, Zero};
+use ark_poly::{polynomial::univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+
+use crate::{
+    mat::{self, LinearMap, Rows},
+    poly,
+};
+
+/// Checks whether the argument is an irreducible polynomial by means of its factorisation,
+/// i.e. by checking that it is square-free and that the function "poly::distinct_degree_factors"
+/// finds no factors of degree less than the degree of the argument. Polynomials of degree 0
+/// are not considered irreducible.
+pub fn irreducibility<F: PrimeField>(c: &DensePolynomial<F>) -> bool {
+    if c.is_zero() || (c.degree() == 0) {
+        return false;
+    }
+    if poly::gcd(c, &poly::derivative(c)).degree() > 0 {
+        // The polynomial has a repeated factor
+        return false;
+    }
+    match poly::distinct_degree_factors(c) {
+        Some(f) => (f.len() == 1) && (f[0].1 == c.degree()),
+        None => false,
+    }
+}
+
+/// Computes the minimal polynomial of the specified square matrix by means of finding the
+/// smallest power of the matrix, which is a linear combination of the lower ones. The powers
+/// are flattened into the columns of an auxiliary matrix, whose kernel is computed by means
+/// of the function "mat::kernel" after appending each column. If the argument is not a
+/// square matrix, then None is returned.
+pub fn minimal_polynomial<F: Field>(a: &(impl Rows<F> + ?Sized)) -> Option<DensePolynomial<F>> {
+    let n = a.row_count();
+    if a.row_length() != Some(n) {
+        // The argument is not a square matrix
+        return None;
+    }
+    let mut p = (0..n)
+        .map(|y| (0..n).map(|x| F::from(x == y)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut m = vec![Vec::<F>::with_capacity(n + 1); n * n];
+    for _ in 0..=n {
+        for (y, r) in p.iter().enumerate() {
+            for (x, e) in r.iter().enumerate() {
+                m[y * n + x].push(*e);
+            }
+        }
+        // The previous powers are linearly independent, so the kernel is either trivial
+        // or spanned by the vector, whose last entry is 1, and this vector contains the
+        // coefficients of the minimal polynomial
+        if let Some(v) = mat::kernel(&m)?.pop() {
+            return Some(DensePolynomial::from_coefficients_vec(v));
+        }
+        p = mat::product_matrix(&p, a)?;
+    }
+    // The Cayley-Hamilton theorem ensures that this point is unreachable
+    None
+}
+
+/// Computes the same value as the function "crate::security_level" for the same arguments
+/// by means of checking the definition of the unconditional P-SPN security level literally.
+/// For each positive i, which does not exceed the second argument, the i-th power of the
+/// matrix is computed by means of the function "mat::product_matrix", and its minimal
+/// polynomial is checked for being of maximum degree and irreducible by means of the
+/// functions "minimal_polynomial" and "irreducibility". If the first argument is not a
+/// square matrix of order at least 2, the second argument is 0 or the matrix is not
+/// unconditionally P-SPN secure, then None is returned. The entries of the matrix are
+/// obtained as the products of the matrix and the standard basis vectors, so the matrix
+/// may be given by any type implementing the trait "mat::LinearMap".
+pub fn security_level<F: PrimeField>(a: &(impl LinearMap<F> + ?Sized), l: u32) -> Option<u32> {
+    let n = a.order()?;
+    if (n < 2) || (l == 0) {
+        // The first argument is not a matrix, which can be used
+        // in P-SPN, or the second argument is 0
+        return None;
+    }
+    // The products of the matrix and the standard basis vectors are its columns
+    let mut b = vec![vec![F::ZERO; n]; n];
+    let (mut u, mut w) = (vec![F::ZERO; n], vec![F::ZERO; n]);
+    for x in 0..n {
+        u[x] = F::ONE;
+        a.image(&u, &mut w);
+        u[x] = F::ZERO;
+        for (r, e) in b.iter_mut().zip(w.iter()) {
+            r[x] = *e;
+        }
+    }
+    let a = b;
+    let mut p = a.clone();
+    for i in 1..=l {
+        let c = minimal_polynomial(&p)?;
+        if (c.degree() != n) || !irreducibility(&c) {
+            // The minimal polynomial of the current power of the matrix
+            // is not of maximum degree or not irreducible
+            return (i > 1).then_some(i - 1);
+        }
+        p = mat::product_matrix(&p, &a)?;
+    }
+    Some(l)
+}
@@ -0,0 +1,239 @@
+//! Provides the quotient ring of polynomials modulo a fixed polynomial, which reduces
+//! polynomials by means of the precomputed reciprocal of the reversed modulus instead
+//! of the polynomial division and reuses its scratch buffers between the operations, so
+//! only the Karatsuba method and the number theoretic transform used for the products of
+//! the large degrees allocate the temporary values.
+
+use ark_ff::{PrimeField, Zero};
+use ark_poly::{polynomial::univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+
+use crate::poly;
+
+/// Represents the quotient ring F\[x\]/(m) for the fixed modulus m of positive degree. The
+/// reduction of a polynomial a of degree k + n - 1, where n is the degree of m, computes the
+/// quotient as the reversal of the product of the reversal of a and the reciprocal of the
+/// reversal of m modulo x^k, as in the Barrett reduction. The reciprocal is computed by
+/// means of the Newton iteration and extended on demand, so the reductions do not divide.
+#[derive(Clone, Debug)]
+pub struct ModRing<F: PrimeField> {
+    m: DensePolynomial<F>,
+    i: Vec<F>,
+    t: Vec<F>,
+    q: Vec<F>,
+    s: Vec<F>,
+}
+
+impl<F: PrimeField> ModRing<F> {
+    /// Creates the quotient ring modulo the argument. If the degree
+    /// of the argument is 0, then None is returned.
+    pub fn new(m: &DensePolynomial<F>) -> Option<Self> {
+        if m.is_zero() || (m.degree() == 0) {
+            return None;
+        }
+        Some(Self {
+            m: m.clone(),
+            // The leading coefficient is not 0, so it is invertible
+            i: vec![m.coeffs[m.degree()].inverse().unwrap()],
+            t: Vec::new(),
+            q: Vec::new(),
+            s: Vec::new(),
+        })
+    }
+
+    /// Returns the modulus of the ring.
+    pub fn modulus(&self) -> &DensePolynomial<F> {
+        &self.m
+    }
+
+    /// Computes the argument modulo the modulus of the ring.
+    pub fn reduced(&mut self, a: &DensePolynomial<F>) -> DensePolynomial<F> {
+        self.t.clear();
+        self.t.extend_from_slice(&a.coeffs);
+        self.reduce();
+        DensePolynomial::from_coefficients_slice(&self.t)
+    }
+
+    /// Computes the product of the arguments modulo the modulus of the ring.
+    pub fn product(
+        &mut self,
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+    ) -> DensePolynomial<F> {
+        self.multiply(&a.coeffs, &b.coeffs);
+        DensePolynomial::from_coefficients_slice(&self.t)
+    }
+
+    /// Computes the first argument raised to the power of the second one modulo the modulus
+    /// of the ring by means of the sliding window exponentiation method, whose window width
+    /// is chosen by the bit length of the exponent. The second argument is an unsigned integer
+    /// represented by its 64-bit chunks stored in the little-endian order.
+    pub fn power(&mut self, a: &DensePolynomial<F>, e: &[u64]) -> DensePolynomial<F> {
+        let b = crate::num::reversed_bits(e).collect::<Vec<_>>();
+        let w = match b.len() {
+            0..=8 => 1,
+            9..=24 => 2,
+            25..=80 => 3,
+            81..=240 => 4,
+            _ => 5,
+        };
+        // Precomputing the odd powers of the base, which are used for the windows
+        let mut o = Vec::<Vec<F>>::with_capacity(1 << (w - 1));
+        self.t.clear();
+        self.t.extend_from_slice(&a.coeffs);
+        self.reduce();
+        o.push(self.t.clone());
+        if w > 1 {
+            self.multiply(&o[0], &o[0]);
+            let s = self.t.clone();
+            for j in 1..1 << (w - 1) {
+                self.multiply(&o[j - 1], &s);
+                o.push(self.t.clone());
+            }
+        }
+        // The modulus is not a constant, so 1 is reduced
+        let mut r = vec![F::ONE];
+        let mut i = 0;
+        while i < b.len() {
+            if !b[i] {
+                self.multiply(&r, &r);
+                std::mem::swap(&mut self.t, &mut r);
+                i += 1;
+                continue;
+            }
+            // Choosing the longest window, which starts at the
+            // current bit, ends with the set bit and fits the width
+            let mut j = (i + w).min(b.len()) - 1;
+            while !b[j] {
+                j -= 1;
+            }
+            for _ in i..=j {
+                self.multiply(&r, &r);
+                std::mem::swap(&mut self.t, &mut r);
+            }
+            let v = b[i..=j].iter().fold(0, |v, e| (v << 1) | *e as usize);
+            self.multiply(&r, &o[v >> 1]);
+            std::mem::swap(&mut self.t, &mut r);
+            i = j + 1;
+        }
+        DensePolynomial::from_coefficients_vec(r)
+    }
+
+    // Computes the product of the arguments in the scratch buffer and reduces it
+    fn multiply(&mut self, a: &[F], b: &[F]) {
+        self.t.clear();
+        if a.is_empty() || b.is_empty() {
+            return;
+        }
+        poly::convolution_into(a, b, &mut self.t);
+        self.reduce();
+    }
+
+    // Extends the reciprocal, so that the polynomials of degree less than n + k, where n is
+    // the degree of the modulus, are reduced by "reduce_into" without extending it
+    pub(crate) fn reserve(&mut self, k: usize) {
+        self.extend(k);
+    }
+
+    // Reduces the polynomial in the first argument modulo the modulus by means of the
+    // scratch buffers specified by the other arguments, so the ring is not modified, which
+    // requires the reciprocal to be extended by "reserve" to the degree of the polynomial
+    pub(crate) fn reduce_into(&self, t: &mut Vec<F>, q: &mut Vec<F>, s: &mut Vec<F>) {
+        while t.last().is_some_and(|e| e.is_zero()) {
+            t.pop();
+        }
+        let (n, l) = (self.m.degree(), t.len());
+        if l <= n {
+            return;
+        }
+        let k = l - n;
+        // Computing the reversal of the quotient as the product of the reversal
+        // of the polynomial and the reciprocal of the reversal of the modulus
+        q.clear();
+        q.extend(t[n..].iter().rev());
+        poly::convolution_into(q, &self.i[..k], s);
+        s.truncate(k);
+        s.reverse();
+        // Subtracting the product of the quotient and the modulus, whose
+        // coefficients at the positions no less than n are known to be 0,
+        // so only the lowest n coefficients of the factors are involved
+        poly::convolution_into(&s[..k.min(n)], &self.m.coeffs[..n], q);
+        t.truncate(n);
+        t.iter_mut().zip(q.iter()).for_each(|(x, y)| *x -= y);
+        while t.last().is_some_and(|e| e.is_zero()) {
+            t.pop();
+        }
+    }
+
+    // Multiplies the first argument by the second one modulo the modulus in place
+    pub(crate) fn product_assign(&mut self, a: &mut Vec<F>, b: &[F]) {
+        self.multiply(a, b);
+        std::mem::swap(&mut self.t, a);
+    }
+
+    // Replaces the modulus by the polynomial of positive degree, whose coefficients are
+    // specified and the last of them is not 0, so that the buffers of the ring are reused
+    pub(crate) fn reset(&mut self, m: &[F]) {
+        self.m.coeffs.clear();
+        self.m.coeffs.extend_from_slice(m);
+        self.i.clear();
+        // The leading coefficient is not 0, so it is invertible
+        self.i.push(m[m.len() - 1].inverse().unwrap());
+    }
+
+    // Computes x raised to the power of the first argument, which is represented as in the
+    // method "power", modulo the modulus in the second argument by means of the binary
+    // exponentiation method, so that the multiplications by x are the shifts
+    pub(crate) fn x_power_into(&mut self, e: &[u64], c: &mut Vec<F>) {
+        c.clear();
+        // The modulus is not a constant, so 1 is reduced
+        c.push(F::ONE);
+        for b in crate::num::reversed_bits(e) {
+            self.multiply(c, c);
+            if b {
+                self.t.insert(0, F::ZERO);
+                self.reduce();
+            }
+            std::mem::swap(&mut self.t, c);
+        }
+    }
+
+    // Reduces the polynomial in the scratch buffer modulo the modulus
+    fn reduce(&mut self) {
+        let (n, l) = (self.m.degree(), self.t.len());
+        if l > n {
+            self.extend(l - n);
+        }
+        let (mut t, mut q, mut s) = (
+            std::mem::take(&mut self.t),
+            std::mem::take(&mut self.q),
+            std::mem::take(&mut self.s),
+        );
+        self.reduce_into(&mut t, &mut q, &mut s);
+        (self.t, self.q, self.s) = (t, q, s);
+    }
+
+    // Extends the reciprocal of the reversal of the modulus to the specified
+    // precision by means of the Newton iteration g -> g - g * (f * g - 1),
+    // which doubles the number of the correct coefficients
+    fn extend(&mut self, k: usize) {
+        let n = self.m.degree();
+        let f = |u: usize| self.m.coeffs[n - u];
+        while self.i.len() < k {
+            let (t, s) = (self.i.len(), (2 * self.i.len()).min(k));
+            // The coefficients of f * g - 1 at the positions less than t are 0, and
+            // the others are stored in the scratch buffer, which is free at this point
+            self.s.clear();
+            self.s.extend((t..s).map(|x| {
+                (x.saturating_sub(t - 1)..=x.min(n))
+                    .map(|u| f(u) * self.i[x - u])
+                    .sum::<F>()
+            }));
+            for x in t..s {
+                let c = (0..=x - t)
+                    .map(|j| self.i[j] * self.s[x - j - t])
+                    .sum::<F>();
+                self.i.push(-c);
+            }
+        }
+    }
+}
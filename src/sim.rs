@@ -0,0 +1,233 @@
+//! Provides the tools for simulating toy P-SPNs over small prime fields, which allow
+//! validating the outcomes of the MDSECheck method empirically by means of tracking
+//! the truncated differentials over many rounds.
+
+use ark_ff::PrimeField;
+use rand::Rng;
+
+use crate::{
+    mat::{self, Rows},
+    num,
+};
+
+/// Represents a toy P-SPN, whose rounds consist of the partial substitution layer applying
+/// the power map x^d to the first s elements of the state, the linear layer multiplying the
+/// state by the specified square matrix and the addition of the random round constants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Spn<F: PrimeField> {
+    m: Vec<Vec<F>>,
+    s: usize,
+    d: u64,
+    c: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> Spn<F> {
+    /// Creates the P-SPN with the linear layer matrix, the number of the S-boxes, the S-box
+    /// exponent and the number of rounds specified by the first four arguments respectively.
+    /// The round constants are generated by means of the source of randomness specified by
+    /// the fifth argument. If the first argument is not a square matrix of order at least 2,
+    /// the number of the S-boxes is not between 1 and the matrix order or the power map
+    /// with the specified exponent is not a permutation, then None is returned.
+    pub fn new(
+        m: &(impl Rows<F> + ?Sized),
+        s: usize,
+        d: u64,
+        l: u32,
+        r: &mut (impl Rng + ?Sized),
+    ) -> Option<Self> {
+        let n = m.row_count();
+        if (n < 2) || (m.row_length() != Some(n)) || (s == 0) || (s > n) {
+            // The first argument is not a matrix, which can be used
+            // in P-SPN, or the number of the S-boxes is not valid
+            return None;
+        }
+        // The power map is a permutation if and only if the exponent
+        // is coprime to the order of the multiplicative group, i.e. p - 1
+        let b = num::residue(F::MODULUS.as_ref(), d)?;
+        if num::gcd(d, if b == 0 { d - 1 } else { b - 1 }) != 1 {
+            return None;
+        }
+        Some(Self {
+            m: m.rows().map(|e| e.to_vec()).collect(),
+            s,
+            d,
+            c: (0..l)
+                .map(|_| (0..n).map(|_| F::rand(r)).collect())
+                .collect(),
+        })
+    }
+
+    /// Returns the number of rounds.
+    pub fn rounds(&self) -> u32 {
+        self.c.len() as u32
+    }
+
+    /// Computes the image of the specified state under the P-SPN. If the length
+    /// of the argument is not the matrix order, then None is returned.
+    pub fn permutation(&self, x: &[F]) -> Option<Vec<F>> {
+        if x.len() != self.m.len() {
+            return None;
+        }
+        let mut v = x.to_vec();
+        for c in self.c.iter() {
+            v.iter_mut().take(self.s).for_each(|e| *e = e.pow([self.d]));
+            v = mat::product_vector(&self.m, &v)?;
+            v.iter_mut().zip(c.iter()).for_each(|(e, k)| *e += k);
+        }
+        Some(v)
+    }
+}
+
+/// Contains the outcome of the empirical validation performed by means of the function
+/// "validation", so that it can be compared with the outcome of the MDSECheck method.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report<F: PrimeField> {
+    /// The input difference and the basis of the proper subspace spanned by the
+    /// corresponding output differences, which form an empirically found truncated
+    /// differential with probability 1, or None, if no such differential is found
+    pub differential: Option<(Vec<F>, Vec<Vec<F>>)>,
+    /// The output of the function "crate::security_level" for the matrix
+    /// of the P-SPN and the bound equal to the number of its rounds
+    pub level: Option<u32>,
+}
+
+/// Computes the basis of the subspace spanned by the output differences of the specified
+/// P-SPN for the input difference specified by the second argument, which are obtained for
+/// the number of the random input pairs specified by the third argument using the source of
+/// randomness specified by the fourth argument. The sampling is stopped early, once the
+/// subspace is the whole space. If the input difference is zero or its length is not the
+/// matrix order, then None is returned.
+pub fn output_span<F: PrimeField>(
+    p: &Spn<F>,
+    v: &[F],
+    k: usize,
+    r: &mut (impl Rng + ?Sized),
+) -> Option<Vec<Vec<F>>> {
+    Some(sampled_span(p, v, k, r)?.0)
+}
+
+/// Creates the list of the nonzero vectors of the specified length, whose first nonzero
+/// entry is 1, i.e. the representatives of all one-dimensional subspaces, which are
+/// sufficient to examine all input differences of a P-SPN up to scalar multiples. If the
+/// length is 0 or the number of such vectors exceeds the second argument, then None is
+/// returned.
+pub fn projective_differences<F: PrimeField>(n: usize, k: usize) -> Option<Vec<Vec<F>>> {
+    let m = F::MODULUS;
+    if (n == 0) || m.as_ref()[1..].iter().any(|c| *c != 0) {
+        // The length is 0 or the field is too large
+        return None;
+    }
+    let p = m.as_ref()[0];
+    let mut d = Vec::new();
+    for i in (0..n).rev() {
+        // Enumerating the vectors, whose first nonzero entry is at the position n - i - 1
+        let c = p.checked_pow(i as u32)?;
+        if d.len() + c as usize > k {
+            return None;
+        }
+        for j in 0..c {
+            let mut v = vec![F::ZERO; n];
+            v[n - i - 1] = F::ONE;
+            for (t, e) in v.iter_mut().rev().take(i).enumerate() {
+                *e = F::from(j / p.pow(t as u32) % p);
+            }
+            d.push(v);
+        }
+    }
+    Some(d)
+}
+
+/// Simulates the P-SPN with the specified arguments of the function "Spn::new" in order
+/// to check empirically whether a truncated differential with probability 1 exists, and
+/// reports the outcome along with the output of the function "crate::security_level" for
+/// the same matrix. The input differences are all vectors created by the function
+/// "projective_differences", if there are at most the fifth argument of them, and the
+/// same number of the random nonzero vectors otherwise. For each input difference, the
+/// output differences are sampled as by the function "output_span" for the number of the
+/// input pairs specified by the sixth argument, and their span is reported, only if it
+/// is a proper subspace, which has not grown during the last 8 samples, so that a span,
+/// which is proper only due to the lack of samples, is not mistaken for a differential.
+/// If the arguments are not valid for "Spn::new" or the sixth argument is less than
+/// the matrix order, then None is returned.
+pub fn validation<F: PrimeField>(
+    m: &(impl Rows<F> + ?Sized),
+    s: usize,
+    d: u64,
+    l: u32,
+    j: usize,
+    k: usize,
+    r: &mut (impl Rng + ?Sized),
+) -> Option<Report<F>> {
+    let p = Spn::new(m, s, d, l, r)?;
+    let n = m.row_count();
+    if k < n {
+        // The output differences cannot span the whole space
+        return None;
+    }
+    let v = projective_differences(n, j).unwrap_or_else(|| {
+        (0..j)
+            .map(|_| loop {
+                let v = (0..n).map(|_| F::rand(r)).collect::<Vec<_>>();
+                if v.iter().any(|e| !e.is_zero()) {
+                    break v;
+                }
+            })
+            .collect()
+    });
+    let mut e = None;
+    for v in v {
+        let (b, t) = sampled_span(&p, &v, k, r)?;
+        if (b.len() < n) && (t >= SPAN_MARGIN) {
+            e = Some((v, b));
+            break;
+        }
+    }
+    Some(Report {
+        differential: e,
+        level: crate::security_level(&p.m, l),
+    })
+}
+
+// The number of the last samples, during which the span of the output differences must
+// not grow, so that it is reported by the function "validation" as a proper subspace
+const SPAN_MARGIN: usize = 8;
+
+// Computes the same basis as the function "output_span" together with the number of the
+// samples, which have been taken after the last one extending the basis
+fn sampled_span<F: PrimeField>(
+    p: &Spn<F>,
+    v: &[F],
+    k: usize,
+    r: &mut (impl Rng + ?Sized),
+) -> Option<(Vec<Vec<F>>, usize)> {
+    let n = p.m.len();
+    if (v.len() != n) || v.iter().all(|e| e.is_zero()) {
+        return None;
+    }
+    let (mut b, mut t) = (Vec::<Vec<F>>::with_capacity(n), 0);
+    for _ in 0..k {
+        let x = (0..n).map(|_| F::rand(r)).collect::<Vec<_>>();
+        let y = x
+            .iter()
+            .zip(v.iter())
+            .map(|(a, b)| *a + b)
+            .collect::<Vec<_>>();
+        let (x, y) = (p.permutation(&x)?, p.permutation(&y)?);
+        b.push(x.iter().zip(y.iter()).map(|(a, b)| *b - a).collect());
+        // Keeping the linearly independent differences
+        // by means of checking the kernel of their matrix
+        let c = (0..n)
+            .map(|j| b.iter().map(|e| e[j]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        if !mat::kernel(&c)?.is_empty() {
+            b.pop();
+            t += 1;
+        } else {
+            t = 0;
+        }
+        if b.len() == n {
+            break;
+        }
+    }
+    Some((b, t))
+}
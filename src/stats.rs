@@ -0,0 +1,121 @@
+//! Provides the tools for collecting the statistics of the outcomes of the MDSECheck
+//! method for the Cauchy matrices over small prime fields, which allow validating
+//! the probability estimates empirically and serve as broad regression corpora.
+
+use ark_ff::PrimeField;
+use rand::Rng;
+
+use crate::{cauchy, random_cauchy, SecurityChecker, Statistics};
+
+/// Contains the distribution of the outcomes of the function "crate::tracked_security_level"
+/// for a family of the matrices, which is collected by means of the functions "exhaustive"
+/// and "sampled" using the same instance of "crate::SecurityChecker" for all matrices.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Distribution {
+    /// The numbers of the matrices grouped by the outcome, where the i-th entry corresponds
+    /// to the security level i, and the entry 0 corresponds to the matrices, which are not
+    /// unconditionally P-SPN secure
+    pub levels: Vec<u64>,
+    /// The numbers of the matrices grouped by the outcome as in the function
+    /// "crate::tracked_security_level", i.e. by the reason of the failure
+    pub statistics: Statistics,
+}
+
+impl Distribution {
+    /// Returns the total number of the checked matrices.
+    pub fn total(&self) -> u64 {
+        self.levels.iter().sum()
+    }
+
+    fn record<F: PrimeField>(&mut self, m: &[Vec<F>], c: &mut SecurityChecker<F>) {
+        let i = c.tracked_check(m, &mut self.statistics).unwrap_or(0);
+        self.levels[i as usize] += 1;
+    }
+}
+
+/// Collects the distribution of the security levels bounded by the second argument for
+/// all Cauchy matrices of the order specified by the first argument. Since adding the same
+/// element to all points does not change the Cauchy matrix, only the tuples of the distinct
+/// points, whose first point is 0, are enumerated, so each matrix is counted once, and there
+/// are (p - 1)! / (p - 2n)! of them, where p is the field size and n is the matrix order.
+/// The used points are tracked by means of a table, whose size is the field size, so the
+/// fields with more than 2^16 elements, for which the enumeration is infeasible anyway, are
+/// not supported. If the first argument is less than 2, the second argument is 0, the field
+/// has more than 2^16 elements or does not have enough elements for the specified matrix
+/// order, then None is returned.
+pub fn exhaustive<F: PrimeField>(n: u32, l: u32) -> Option<Distribution> {
+    let m = F::MODULUS;
+    if (n < 2) || (l == 0) || (m > F::BigInt::from(MAX_FIELD_SIZE)) {
+        // The arguments are not valid, or the field is too large
+        return None;
+    }
+    let (p, n) = (m.as_ref()[0], n as usize);
+    if p < 2 * n as u64 {
+        // The field does not have enough elements for the specified matrix order
+        return None;
+    }
+    let mut c = SecurityChecker::new(n as u32, l)?;
+    let mut d = Distribution {
+        levels: vec![0; l as usize + 1],
+        ..Default::default()
+    };
+    // Enumerating the tuples of the distinct points in the lexicographic order
+    let mut t = vec![0u64];
+    let mut u = vec![false; p as usize];
+    u[0] = true;
+    loop {
+        if t.len() == 2 * n {
+            let v = t.iter().map(|e| F::from(*e)).collect::<Vec<_>>();
+            d.record(&cauchy(&v[..n], &v[n..])?, &mut c);
+        } else {
+            // Extending the tuple by the smallest unused point
+            let e = u.iter().position(|e| !e)? as u64;
+            u[e as usize] = true;
+            t.push(e);
+            continue;
+        }
+        // Replacing the last points by the next unused ones, and
+        // removing them, while there are no such points
+        loop {
+            let e = t.pop()?;
+            u[e as usize] = false;
+            if t.is_empty() {
+                return Some(d);
+            }
+            if let Some(e) = (e + 1..p).find(|e| !u[*e as usize]) {
+                u[e as usize] = true;
+                t.push(e);
+                break;
+            }
+        }
+    }
+}
+
+// The largest field size supported by the function "exhaustive"
+const MAX_FIELD_SIZE: u64 = 1 << 16;
+
+/// Collects the distribution of the security levels bounded by the second argument for the
+/// number of the random Cauchy matrices specified by the third argument, whose order is the
+/// first argument, by means of the function "crate::random_cauchy" using the source of
+/// randomness specified by the fourth argument. If the first argument is less than 2, the
+/// second argument is 0 or the field does not have enough elements for the specified matrix
+/// order, then None is returned.
+pub fn sampled<F: PrimeField>(
+    n: u32,
+    l: u32,
+    k: u64,
+    r: &mut (impl Rng + ?Sized),
+) -> Option<Distribution> {
+    if (n < 2) || (l == 0) || (F::MODULUS < F::BigInt::from(n as u64 * 2)) {
+        return None;
+    }
+    let mut c = SecurityChecker::new(n, l)?;
+    let mut d = Distribution {
+        levels: vec![0; l as usize + 1],
+        ..Default::default()
+    };
+    for _ in 0..k {
+        d.record(&random_cauchy::<F>(n, r)?, &mut c);
+    }
+    Some(d)
+}
@@ -0,0 +1,226 @@
+//! Provides the implementation of the algorithms for detecting infinitely long subspace
+//! trails of P-SPNs, which are proposed in the paper "Proving Resistance Against Infinitely
+//! Long Subspace Trails: How to Choose the Linear Layer" by L. Grassi, C. Rechberger and
+//! M. Schofnegger. Unlike the MDSECheck method, these algorithms take into account the
+//! number s of the S-boxes in the partial substitution layer, which are assumed to be
+//! applied to the first s elements of the state, as in the reference scripts of Poseidon.
+//! The module also provides the tools for checking the conditional security, i.e. the
+//! resistance of a P-SPN with the S-boxes at the concrete positions.
+
+use ark_ff::Field;
+
+use crate::mat::{self, Rows};
+
+/// Computes the basis of some nontrivial proper subspace invariant under the specified
+/// square matrix, which is an infinitely long subspace trail with active S-boxes, in
+/// accordance with Algorithm 2 of the paper. The S-boxes are the first ones, and their
+/// number is the second argument. For each nonempty set of the S-box positions, the
+/// smallest invariant subspace containing the standard basis vectors of these positions is
+/// computed, and it is a trail, if its elements have zero entries at the other S-box positions,
+/// because the differences of the S-box outputs at the chosen positions remain in it. If
+/// there is no such subspace, then the basis is empty. Since the number of the examined
+/// sets is 2^s - 1, where s is the number of the S-boxes, at most 63 S-boxes are supported.
+/// If the first argument is not a square matrix of order at least 2 or the second argument
+/// is not between 1 and the matrix order or exceeds 63, then None is returned.
+pub fn active_trail<F: Field>(a: &(impl Rows<F> + ?Sized), s: usize) -> Option<Vec<Vec<F>>> {
+    let n = order(a, s)?;
+    if s >= 64 {
+        // The number of the S-box position sets is too large
+        return None;
+    }
+    for j in 1..1u64 << s {
+        let mut b = Basis::default();
+        let mut q = (0..s)
+            .filter(|l| j & (1 << l) != 0)
+            .map(|l| (0..n).map(|x| F::from(x == l)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        // Computing the smallest invariant subspace containing the chosen
+        // standard basis vectors by means of extending it with the images
+        // of the newly added basis vectors until the subspace stabilises
+        let mut t = false;
+        while let Some(v) = q.pop() {
+            if !b.insert(&v) {
+                continue;
+            }
+            if (0..s).any(|l| (j & (1 << l) == 0) && (v[l] != F::ZERO)) || (b.len() == n) {
+                // The subspace contains a difference activating some S-box,
+                // which is not chosen, or the subspace is the whole space
+                t = true;
+                break;
+            }
+            q.push(mat::product_vector(a, &v)?);
+        }
+        if !t {
+            return Some(b.vectors());
+        }
+    }
+    Some(vec![])
+}
+
+/// Computes the smallest positive number of rounds, which does not exceed the third argument
+/// and during which each nonzero difference activates at least one of the S-boxes at the
+/// positions specified by the second argument, in a P-SPN using the specified square matrix
+/// as the component of its linear layers. This is the conditional counterpart of the security
+/// level, since it depends on the concrete partial substitution layer, and the subspaces of
+/// the differences avoiding the S-boxes are computed by means of the function
+/// "inactive_subspace". These subspaces become invariant under the matrix, once they stop
+/// shrinking, so in this case the number of rounds is unbounded. If there is no such number
+/// of rounds or the arguments are not valid for "inactive_subspace", then None is returned.
+pub fn conditional_level<F: Field>(
+    a: &(impl Rows<F> + ?Sized),
+    p: &[usize],
+    l: u32,
+) -> Option<u32> {
+    // Checking the arguments and obtaining the dimension of the whole space
+    let mut d = inactive_subspace(a, p, 0)?.len();
+    let (mut m, mut c) = (identity(d), Vec::with_capacity(d * p.len()));
+    for r in 1..=l {
+        // Appending the rows of the matrix power, which
+        // is used in the current round, at the S-box positions
+        c.extend(p.iter().map(|y| m[*y].clone()));
+        let e = mat::kernel(&c)?.len();
+        if e == 0 {
+            return Some(r);
+        }
+        if e == d {
+            // The subspace has stopped shrinking, so it
+            // is an infinitely long inactive subspace trail
+            return None;
+        }
+        d = e;
+        if r < l {
+            m = mat::product_matrix(&m, a)?;
+        }
+    }
+    None
+}
+
+/// Computes the basis of the subspace of the differences, which activate none of the
+/// S-boxes at the positions specified by the second argument during the number of rounds
+/// specified by the third argument, in a P-SPN using the specified square matrix as the
+/// component of its linear layers. Each round consists of the substitution layer and the
+/// linear layer, so the subspace is the kernel of the vertical concatenation of the rows
+/// of the powers of the matrix, whose exponents are less than the number of rounds, at the
+/// S-box positions. If the first argument is not a square matrix of order at least 2 or
+/// the list of the S-box positions is empty or contains positions not less than the matrix
+/// order, then None is returned.
+pub fn inactive_subspace<F: Field>(
+    a: &(impl Rows<F> + ?Sized),
+    p: &[usize],
+    r: u32,
+) -> Option<Vec<Vec<F>>> {
+    let n = a.row_count();
+    if (n < 2) || (a.row_length() != Some(n)) || p.is_empty() || p.iter().any(|y| *y >= n) {
+        // The first argument is not a matrix, which can be used
+        // in P-SPN, or the S-box positions are not valid
+        return None;
+    }
+    let mut m = identity(n);
+    let mut c = Vec::with_capacity(r as usize * p.len());
+    for i in 0..r {
+        if i > 0 {
+            m = mat::product_matrix(&m, a)?;
+        }
+        c.extend(p.iter().map(|y| m[*y].clone()));
+    }
+    if c.is_empty() {
+        // No rounds are performed, so each difference avoids the S-boxes
+        return Some(identity(n));
+    }
+    mat::kernel(&c)
+}
+
+/// Computes the basis of the largest subspace invariant under the specified square matrix,
+/// whose elements have zero entries at the positions of the S-boxes, which are the first
+/// ones, and whose number is the second argument, in accordance with Algorithm 1 of the
+/// paper. A nonzero difference from this subspace never activates S-boxes, so the subspace
+/// is an infinitely long subspace trail, and the basis is empty if there is no such trail.
+/// The subspace is computed by means of the function "inactive_subspace" for the number
+/// of rounds equal to the matrix order, since the higher powers of the matrix are linear
+/// combinations of the lower ones. If the first argument is not a square matrix of order
+/// at least 2 or the second argument is not between 1 and the matrix order, then None is
+/// returned.
+pub fn inactive_trail<F: Field>(a: &(impl Rows<F> + ?Sized), s: usize) -> Option<Vec<Vec<F>>> {
+    let n = order(a, s)?;
+    inactive_subspace(a, &(0..s).collect::<Vec<_>>(), n as u32)
+}
+
+/// Computes the largest positive number, which does not exceed the third argument and
+/// for each positive i, which does not exceed this number, the functions "inactive_trail"
+/// and "active_trail" do not detect the subspace trails for the specified square matrix
+/// raised to the power of i and the number of the S-boxes specified by the second argument.
+/// This corresponds to Algorithm 3 of the paper, which checks the powers of the matrix
+/// for the invariant subspaces of the aforementioned kinds, so the result can be compared
+/// with the output of the function "crate::security_level". If a trail is detected for
+/// the matrix itself or the arguments are not valid for the aforementioned functions or
+/// the third argument is 0, then None is returned.
+pub fn security_level<F: Field>(a: &(impl Rows<F> + ?Sized), s: usize, l: u32) -> Option<u32> {
+    if l == 0 {
+        return None;
+    }
+    let mut p = a.rows().map(|r| r.to_vec()).collect::<Vec<_>>();
+    for i in 1..=l {
+        if !inactive_trail(&p, s)?.is_empty() || !active_trail(&p, s)?.is_empty() {
+            return (i > 1).then_some(i - 1);
+        }
+        if i < l {
+            p = mat::product_matrix(&p, a)?;
+        }
+    }
+    Some(l)
+}
+
+// Checks the arguments of the trail detection functions and returns the matrix order
+fn order<F: Field>(a: &(impl Rows<F> + ?Sized), s: usize) -> Option<usize> {
+    let n = a.row_count();
+    if (n < 2) || (a.row_length() != Some(n)) || (s == 0) || (s > n) {
+        // The first argument is not a matrix, which can be used
+        // in P-SPN, or the number of the S-boxes is not valid
+        return None;
+    }
+    Some(n)
+}
+
+// Creates the identity matrix of the specified order
+fn identity<F: Field>(n: usize) -> Vec<Vec<F>> {
+    (0..n)
+        .map(|y| (0..n).map(|x| F::from(x == y)).collect())
+        .collect()
+}
+
+// Represents the subspace basis in the row echelon form
+#[derive(Default)]
+struct Basis<F: Field> {
+    r: Vec<(usize, Vec<F>)>,
+    v: Vec<Vec<F>>,
+}
+
+impl<F: Field> Basis<F> {
+    // Adds the vector, if it is not in the span, and returns whether it has been added
+    fn insert(&mut self, v: &[F]) -> bool {
+        let mut u = v.to_vec();
+        for (p, r) in self.r.iter() {
+            let c = u[*p];
+            if c != F::ZERO {
+                u.iter_mut().zip(r.iter()).for_each(|(x, y)| *x -= c * y);
+            }
+        }
+        if let Some(p) = u.iter().position(|x| *x != F::ZERO) {
+            let c = u[p].inverse().unwrap();
+            u.iter_mut().for_each(|x| *x *= c);
+            self.r.push((p, u));
+            self.v.push(v.to_vec());
+            true
+        } else {
+            false
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.v.len()
+    }
+
+    fn vectors(self) -> Vec<Vec<F>> {
+        self.v
+    }
+}
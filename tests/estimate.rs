@@ -0,0 +1,68 @@
+use ark_bn254::Fr;
+use ark_ff::{Fp64, MontBackend, MontConfig};
+use mdsecheck::{
+    estimate::{calibration, expected_attempts, probability, run_time},
+    stats,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[derive(MontConfig)]
+#[modulus = "13"]
+#[generator = "2"]
+pub struct F13Config;
+pub type F13 = Fp64<MontBackend<F13Config, 1>>;
+
+#[derive(MontConfig)]
+#[modulus = "31"]
+#[generator = "3"]
+pub struct F31Config;
+pub type F31 = Fp64<MontBackend<F31Config, 1>>;
+
+#[test]
+fn test_calibration() {
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    assert_eq!(calibration::<F13>(3, 6, 0, &mut r), None);
+    assert_eq!(calibration::<F13>(1, 6, 10, &mut r), None);
+    assert_eq!(calibration::<F13>(3, 0, 10, &mut r), None);
+    assert!(calibration::<F13>(3, 6, 10, &mut r).is_some());
+}
+
+#[test]
+fn test_expected_attempts() {
+    assert_eq!(expected_attempts::<F13>(7, 6), None);
+    let a = expected_attempts::<F13>(2, 6).unwrap();
+    assert!((a - 2197.0 / 864.0).abs() < 1e-9);
+    let a = expected_attempts::<Fr>(5, 25).unwrap();
+    assert!((a - 5.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_probability() {
+    assert_eq!(probability::<F13>(1, 6), None);
+    assert_eq!(probability::<F13>(2, 0), None);
+    assert_eq!(probability::<F13>(7, 6), None);
+    // There are 78 monic irreducible quadratic polynomials over F13, and 12
+    // of 156 elements of degree 2 have the squares belonging to F13
+    let e = 78.0 / 169.0 * 12.0 / 13.0 * 12.0 / 13.0;
+    assert!((probability::<F13>(2, 1).unwrap() - 78.0 / 169.0 * 12.0 / 13.0).abs() < 1e-9);
+    assert!((probability::<F13>(2, 6).unwrap() - e).abs() < 1e-9);
+    // For large fields, the probability is close to 1 / n
+    for n in 2..=8 {
+        let p = probability::<Fr>(n, 25).unwrap();
+        assert!((p - 1.0 / n as f64).abs() < 1e-9);
+    }
+    // Comparing with the empirical distribution
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    for n in 3..=4 {
+        let d = stats::sampled::<F31>(n, 8, 400, &mut r).unwrap();
+        let p = probability::<F31>(n, 8).unwrap();
+        assert!((d.levels[8] as f64 / 400.0 - p).abs() < 0.04);
+    }
+}
+
+#[test]
+fn test_run_time() {
+    let mut r = ChaCha8Rng::seed_from_u64(789);
+    assert_eq!(run_time::<F13>(3, 6, 0, &mut r), None);
+    assert!(run_time::<F13>(3, 6, 10, &mut r).is_some());
+}
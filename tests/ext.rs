@@ -0,0 +1,104 @@
+use ark_bn254::Fr;
+use ark_ff::UniformRand;
+use mdsecheck::{ext::ExtensionElement, poly::new};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[test]
+fn test_new() {
+    let m = new::<Fr>(&[1, 0, -5]);
+    assert_eq!(ExtensionElement::new(&new::<Fr>(&[1, 0]), &new(&[0])), None);
+    assert_eq!(ExtensionElement::new(&new::<Fr>(&[1, 0]), &new(&[3])), None);
+    let e = ExtensionElement::new(&new(&[2, 1, 3]), &m).unwrap();
+    assert_eq!(e.value(), &new(&[1, 13]));
+    assert_eq!(e.modulus(), &m);
+}
+
+#[test]
+fn test_arithmetic() {
+    let (m, n) = (new::<Fr>(&[1, 0, -5]), new::<Fr>(&[1, 0, -4]));
+    let a = ExtensionElement::new(&new(&[1, 1]), &m).unwrap();
+    let b = ExtensionElement::new(&new(&[-1, 1]), &m).unwrap();
+    let c = ExtensionElement::new(&new(&[1, 1]), &n).unwrap();
+    assert_eq!(a.sum(&b).unwrap().value(), &new(&[2]));
+    assert_eq!(a.difference(&b).unwrap().value(), &new(&[2, 0]));
+    assert_eq!(a.product(&b).unwrap().value(), &new(&[-4]));
+    assert_eq!(a.sum(&c), None);
+    assert_eq!(a.difference(&c), None);
+    assert_eq!(a.product(&c), None);
+    assert_eq!(a.power(&[0]).value(), &new(&[1]));
+    assert_eq!(a.power(&[2]).value(), &new(&[2, 6]));
+    assert_eq!(
+        ExtensionElement::new(&new(&[1, 0]), &m)
+            .unwrap()
+            .power(&[5])
+            .value(),
+        &new(&[25, 0])
+    );
+}
+
+#[test]
+fn test_inverse() {
+    let (m, n) = (new::<Fr>(&[1, 0, -5]), new::<Fr>(&[1, 0, -4]));
+    assert_eq!(
+        ExtensionElement::new(&new(&[0]), &m).unwrap().inverse(),
+        None
+    );
+    assert_eq!(
+        ExtensionElement::new(&new(&[1, -2]), &n).unwrap().inverse(),
+        None
+    );
+    let a = ExtensionElement::new(&new(&[1, 1]), &m).unwrap();
+    assert_eq!(
+        a.inverse().unwrap().value(),
+        &new(&[Fr::from(1) / Fr::from(4), -Fr::from(1) / Fr::from(4)])
+    );
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    let m = new::<Fr>(&[1, 0, 0, 0, -5]);
+    for _ in 0..10 {
+        let v = (0..4).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>();
+        let a = ExtensionElement::new(&new(&v), &m).unwrap();
+        let b = a.inverse().unwrap();
+        assert_eq!(a.product(&b).unwrap().value(), &new(&[1]));
+    }
+}
+
+#[test]
+fn test_frobenius() {
+    let (m, n) = (new::<Fr>(&[1, 0, -5]), new::<Fr>(&[1, 0, 0, -5]));
+    let a = ExtensionElement::new(&new(&[1, 1]), &m).unwrap();
+    assert_eq!(a.frobenius(0), a);
+    assert_eq!(a.frobenius(1).value(), &new(&[-1, 1]));
+    assert_eq!(a.frobenius(2), a);
+    let b = ExtensionElement::new(&new(&[1, 0]), &n).unwrap();
+    assert_ne!(b.frobenius(1), b);
+    assert_ne!(b.frobenius(2), b);
+    assert_eq!(b.frobenius(3), b);
+}
+
+#[test]
+fn test_trace_norm() {
+    let (m, n) = (new::<Fr>(&[1, 0, -5]), new::<Fr>(&[2, 0, 0, -10]));
+    let a = ExtensionElement::new(&new(&[1, 1]), &m).unwrap();
+    assert_eq!(a.trace(), Fr::from(2));
+    assert_eq!(a.norm(), -Fr::from(4));
+    let z = ExtensionElement::new(&new(&[0]), &n).unwrap();
+    assert_eq!(z.trace(), Fr::from(0));
+    assert_eq!(z.norm(), Fr::from(0));
+    let o = ExtensionElement::new(&new(&[1]), &n).unwrap();
+    assert_eq!(o.trace(), Fr::from(3));
+    assert_eq!(o.norm(), Fr::from(1));
+    let x = ExtensionElement::new(&new(&[1, 0]), &n).unwrap();
+    assert_eq!(x.trace(), Fr::from(0));
+    assert_eq!(x.norm(), Fr::from(5));
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    for _ in 0..10 {
+        let u = (0..3).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>();
+        let v = (0..3).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>();
+        let a = ExtensionElement::new(&new(&u), &n).unwrap();
+        let b = ExtensionElement::new(&new(&v), &n).unwrap();
+        assert_eq!(a.frobenius(1).trace(), a.trace());
+        assert_eq!(a.frobenius(2).norm(), a.norm());
+        assert_eq!(a.sum(&b).unwrap().trace(), a.trace() + b.trace());
+        assert_eq!(a.product(&b).unwrap().norm(), a.norm() * b.norm());
+    }
+}
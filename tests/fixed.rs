@@ -0,0 +1,129 @@
+use ark_bn254::Fr;
+use ark_ff::{Fp64, MontBackend, MontConfig, PrimeField, UniformRand};
+use mdsecheck::{
+    fixed::{product_vector, random_cauchy, security_level, system_solution},
+    mat,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[derive(MontConfig)]
+#[modulus = "13"]
+#[generator = "2"]
+pub struct F13Config;
+pub type F13 = Fp64<MontBackend<F13Config, 1>>;
+
+#[derive(MontConfig)]
+#[modulus = "31"]
+#[generator = "3"]
+pub struct F31Config;
+pub type F31 = Fp64<MontBackend<F31Config, 1>>;
+
+// Creates the random matrix of the fixed order
+fn random_matrix<F: PrimeField, const N: usize>(r: &mut ChaCha8Rng) -> [[F; N]; N] {
+    [[(); N]; N].map(|v| v.map(|_| F::rand(r)))
+}
+
+// Compares the security levels of the random matrices and random Cauchy
+// matrices of the fixed order with the ones computed by the dynamic API
+fn compare_levels<F: PrimeField, const N: usize>(k: usize, l: u32, r: &mut ChaCha8Rng) {
+    for _ in 0..k {
+        let m = random_matrix::<F, N>(r);
+        assert_eq!(security_level(&m, l), mdsecheck::security_level(&m, l));
+        if let Some(m) = random_cauchy::<F, N>(r) {
+            assert_eq!(security_level(&m, l), mdsecheck::security_level(&m, l));
+        }
+    }
+}
+
+#[test]
+fn test_product_vector() {
+    assert_eq!(product_vector::<Fr, 0, 0>(&[], &[]), []);
+    assert_eq!(
+        product_vector(
+            &[
+                [Fr::from(1), Fr::from(2), Fr::from(1)],
+                [Fr::from(3), Fr::from(1), Fr::from(4)],
+            ],
+            &[Fr::from(2), Fr::from(3), Fr::from(5)]
+        ),
+        [Fr::from(13), Fr::from(29)]
+    );
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    let a = random_matrix::<Fr, 20>(&mut r);
+    let v = [(); 20].map(|_| Fr::rand(&mut r));
+    assert_eq!(
+        product_vector(&a, &v).to_vec(),
+        mat::product_vector(&a, &v).unwrap()
+    );
+}
+
+#[test]
+fn test_random_cauchy() {
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    assert_eq!(random_cauchy::<Fr, 0>(&mut r), None);
+    assert_eq!(random_cauchy::<F13, 7>(&mut r), None);
+    // The matrices are the same as the ones created by the dynamic API
+    fn compare<F: PrimeField, const N: usize>(s: u64) {
+        let (mut r, mut t) = (ChaCha8Rng::seed_from_u64(s), ChaCha8Rng::seed_from_u64(s));
+        for _ in 0..5 {
+            assert_eq!(
+                random_cauchy::<F, N>(&mut r).map(|m| m.map(|v| v.to_vec()).to_vec()),
+                mdsecheck::random_cauchy::<F>(N as u32, &mut t)
+            );
+        }
+    }
+    compare::<Fr, 1>(1);
+    compare::<Fr, 4>(2);
+    compare::<Fr, 16>(3);
+    compare::<F13, 3>(4);
+    compare::<F13, 6>(5);
+    compare::<F31, 8>(6);
+}
+
+#[test]
+fn test_security_level() {
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    assert_eq!(security_level::<Fr, 0>(&[], 25), None);
+    assert_eq!(security_level(&[[Fr::from(1)]], 25), None);
+    let m = random_cauchy::<Fr, 4>(&mut r).unwrap();
+    assert_eq!(security_level(&m, 0), None);
+    // The outcomes are the same as the ones of the dynamic API for
+    // the matrices, which fail the check for all possible reasons
+    compare_levels::<F13, 2>(200, 6, &mut r);
+    compare_levels::<F13, 3>(200, 6, &mut r);
+    compare_levels::<F13, 4>(200, 6, &mut r);
+    compare_levels::<F13, 6>(100, 6, &mut r);
+    compare_levels::<F31, 5>(100, 8, &mut r);
+    compare_levels::<F31, 8>(50, 8, &mut r);
+    compare_levels::<Fr, 2>(10, 25, &mut r);
+    compare_levels::<Fr, 5>(10, 25, &mut r);
+    compare_levels::<Fr, 12>(5, 25, &mut r);
+}
+
+#[test]
+fn test_system_solution() {
+    assert_eq!(system_solution::<Fr, 0>(&[], &[]), None);
+    assert_eq!(
+        system_solution(
+            &[[Fr::from(1), Fr::from(2)], [Fr::from(2), Fr::from(4)]],
+            &[Fr::from(1), Fr::from(1)]
+        ),
+        None
+    );
+    assert_eq!(
+        system_solution(
+            &[[Fr::from(0), Fr::from(1)], [Fr::from(1), Fr::from(0)]],
+            &[Fr::from(2), Fr::from(3)]
+        ),
+        Some([Fr::from(3), Fr::from(2)])
+    );
+    let mut r = ChaCha8Rng::seed_from_u64(789);
+    for _ in 0..50 {
+        let a = random_matrix::<F13, 4>(&mut r);
+        let b = [(); 4].map(|_| F13::rand(&mut r));
+        assert_eq!(
+            system_solution(&a, &b).map(|v| v.to_vec()),
+            mat::system_solution(&a, &b)
+        );
+    }
+}
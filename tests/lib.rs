@@ -1,6 +1,147 @@
 use ark_bn254::Fr;
-use mdsecheck::{mat, random_cauchy, security_level};
+use ark_ff::{Field, Fp64, MontBackend, MontConfig, UniformRand};
+use mdsecheck::{
+    batch_inverse, cauchy, invariant_subspaces, mat, poly, random_cauchy, reference,
+    security_level, tracked_security_level, SecurityChecker, Statistics,
+};
 use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+// Counts the allocations of each thread, so that the tests running in parallel do not
+// affect each other
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, l: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|c| c.set(c.get() + 1));
+        System.alloc(l)
+    }
+
+    unsafe fn dealloc(&self, p: *mut u8, l: Layout) {
+        System.dealloc(p, l)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(MontConfig)]
+#[modulus = "13"]
+#[generator = "2"]
+pub struct F13Config;
+pub type F13 = Fp64<MontBackend<F13Config, 1>>;
+
+#[test]
+fn test_batch_inverse() {
+    assert_eq!(batch_inverse::<Fr>(&[]), Some(vec![]));
+    assert_eq!(batch_inverse(&[Fr::from(0)]), None);
+    assert_eq!(
+        batch_inverse(&[Fr::from(2), Fr::from(0), Fr::from(3)]),
+        None
+    );
+    assert_eq!(
+        batch_inverse(&[Fr::from(4)]),
+        Some(vec![Fr::from(4).inverse().unwrap()])
+    );
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    for n in [2, 3, 10, 100] {
+        let v = (0..n).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>();
+        assert_eq!(
+            batch_inverse(&v),
+            Some(v.iter().map(|e| e.inverse().unwrap()).collect())
+        );
+    }
+}
+
+#[test]
+fn test_cauchy() {
+    let (x, y) = ([Fr::from(1), Fr::from(2)], [Fr::from(3), Fr::from(5)]);
+    assert_eq!(cauchy::<Fr>(&[], &[]), None);
+    assert_eq!(cauchy(&x, &y[..1]), None);
+    assert_eq!(cauchy(&x, &[Fr::from(3), Fr::from(1)]), None);
+    assert_eq!(cauchy(&[Fr::from(1), Fr::from(1)], &y), None);
+    assert_eq!(
+        cauchy(&x, &y),
+        Some(vec![
+            vec![-Fr::from(2).inverse().unwrap(), -Fr::from(1)],
+            vec![
+                -Fr::from(4).inverse().unwrap(),
+                -Fr::from(3).inverse().unwrap()
+            ]
+        ])
+    );
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    let v = (0..16).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>();
+    let m = cauchy(&v[..8], &v[8..]).unwrap();
+    for (i, b) in v[8..].iter().enumerate() {
+        for (j, a) in v[..8].iter().enumerate() {
+            assert_eq!(m[i][j] * (*a - b), Fr::from(1));
+        }
+    }
+}
+
+#[test]
+fn test_invariant_subspaces() {
+    let mut r = ChaCha8Rng::seed_from_u64(321);
+    let m = [[Fr::from(0), Fr::from(5)], [Fr::from(1), Fr::from(0)]];
+    assert_eq!(invariant_subspaces(&m, 0, &mut r), None);
+    assert_eq!(invariant_subspaces(&[] as &[&[Fr]], 1, &mut r), None);
+    assert_eq!(invariant_subspaces(&m, 1, &mut r), Some(vec![]));
+    assert_eq!(
+        invariant_subspaces(&mat::Matrix::new(&m).unwrap(), 2, &mut r.clone()),
+        invariant_subspaces(&m, 2, &mut r.clone())
+    );
+    assert_eq!(
+        invariant_subspaces(&m, 2, &mut r),
+        Some(vec![(
+            poly::new(&[1, -5]),
+            vec![vec![Fr::from(1), Fr::from(0)]]
+        )])
+    );
+    assert_eq!(
+        invariant_subspaces(
+            &[[Fr::from(1), Fr::from(0)], [Fr::from(0), Fr::from(2)]],
+            1,
+            &mut r
+        ),
+        Some(vec![
+            (poly::new(&[1, -2]), vec![vec![Fr::from(0), Fr::from(1)]]),
+            (poly::new(&[1, -1]), vec![vec![Fr::from(1), Fr::from(0)]])
+        ])
+    );
+    // Checking the invariance of the subspaces found for the matrices, which are
+    // rejected by the MDSECheck method, and their absence for the accepted matrices
+    let mut s = ChaCha8Rng::seed_from_u64(456);
+    for (_, n) in (0..6).zip((2..=4).cycle()) {
+        let m = random_cauchy::<Fr>(n, &mut s).unwrap();
+        let l = security_level(&m, 3);
+        for i in 1..=3 {
+            let v = invariant_subspaces(&m, i, &mut r).unwrap();
+            assert_eq!(v.is_empty(), l.is_some_and(|l| l >= i));
+            let p = mat::power_matrix(&m, &[i as u64]).unwrap();
+            for (_, b) in v {
+                assert!(!b.is_empty() && (b.len() < n as usize));
+                for u in b.iter() {
+                    // The image is in the subspace, if it together with the basis
+                    // vectors forms a matrix, whose kernel has a nonzero last entry
+                    let w = mat::product_vector(&p, u).unwrap();
+                    let c = (0..n as usize)
+                        .map(|y| b.iter().map(|e| e[y]).chain([w[y]]).collect::<Vec<_>>())
+                        .collect::<Vec<_>>();
+                    let k = mat::kernel(&c).unwrap();
+                    assert!(k.iter().any(|e| e[b.len()] != Fr::from(0)));
+                }
+            }
+        }
+    }
+}
 
 #[test]
 fn test_random_cauchy() {
@@ -13,12 +154,95 @@ fn test_random_cauchy() {
     }
 }
 
+#[test]
+fn test_security_checker() {
+    assert!(SecurityChecker::<Fr>::new(0, 25).is_none());
+    assert!(SecurityChecker::<Fr>::new(1, 25).is_none());
+    assert!(SecurityChecker::<Fr>::new(4, 0).is_none());
+    let mut c = SecurityChecker::<Fr>::new(4, 25).unwrap();
+    assert_eq!((c.order(), c.bound()), (4, 25));
+    let mut r = ChaCha8Rng::seed_from_u64(789);
+    // The matrices of the other orders are not checked
+    assert_eq!(c.check(&random_cauchy::<Fr>(3, &mut r).unwrap()), None);
+    assert_eq!(c.check(&[[Fr::from(1); 4]; 3]), None);
+    // The same checker gives the same results as the functions for many matrices
+    let mut c = (2..=7)
+        .map(|n| SecurityChecker::<Fr>::new(n, 25).unwrap())
+        .collect::<Vec<_>>();
+    let (mut t, mut u) = (Statistics::default(), Statistics::default());
+    for (_, n) in (0..60).zip((2..=7).cycle()) {
+        let m = random_cauchy::<Fr>(n, &mut r).unwrap();
+        let s = c[n as usize - 2].tracked_check(&m, &mut t);
+        assert_eq!(s, tracked_security_level(&m, 25, &mut u));
+        assert_eq!(c[n as usize - 2].check(&mat::Matrix::new(&m).unwrap()), s);
+        let b = mat::CauchyMatrix::new(&[Fr::from(n)], &[Fr::from(0)]).unwrap();
+        assert_eq!(c[n as usize - 2].check(&b), None);
+    }
+    assert_eq!(t, u);
+    assert_eq!(
+        t.secure + t.subfield,
+        60 - t.krylov - t.stickelberger - t.reducible
+    );
+}
+
+// The checks performed with the feature "audit" allocate the reference results
+#[cfg(not(feature = "audit"))]
+#[test]
+fn test_security_checker_allocations() {
+    // The polynomial products for these orders are computed by the schoolbook method, so
+    // once the buffers have grown, the checks of the explicit matrices do not allocate.
+    // The matrices over F13 and the identity matrices cover all outcomes of the checks
+    let mut r = ChaCha8Rng::seed_from_u64(321);
+    let mut t = Statistics::default();
+    for n in 2..=11 {
+        let m = (0..20)
+            .map(|_| random_cauchy::<Fr>(n, &mut r).unwrap())
+            .chain([(0..n)
+                .map(|i| (0..n).map(|j| Fr::from(i == j)).collect())
+                .collect()])
+            .map(|m| mat::Matrix::new(&m).unwrap())
+            .collect::<Vec<_>>();
+        checked_allocations(&mut SecurityChecker::new(n, 25).unwrap(), &m, &mut t);
+    }
+    for n in 2..=6 {
+        let m = (0..20)
+            .map(|_| mat::Matrix::new(&random_cauchy::<F13>(n, &mut r).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        checked_allocations(&mut SecurityChecker::new(n, 20).unwrap(), &m, &mut t);
+    }
+    assert!(
+        [t.krylov, t.stickelberger, t.reducible, t.subfield, t.secure]
+            .iter()
+            .all(|e| *e > 0)
+    );
+}
+
+// Checks the matrices twice and asserts that the second checks do not allocate
+#[cfg(not(feature = "audit"))]
+fn checked_allocations<F: ark_ff::PrimeField>(
+    c: &mut SecurityChecker<F>,
+    m: &[mat::Matrix<F>],
+    t: &mut Statistics,
+) {
+    let s = m.iter().map(|m| c.tracked_check(m, t)).collect::<Vec<_>>();
+    let a = ALLOCATIONS.with(|c| c.get());
+    for (m, s) in m.iter().zip(s.iter()) {
+        assert_eq!(c.check(m), *s);
+    }
+    assert_eq!(ALLOCATIONS.with(|c| c.get()), a);
+}
+
 #[test]
 fn test_security_level() {
     let mut r = ChaCha8Rng::seed_from_u64(456);
     for (i, n) in (0..12).zip((2..=7).cycle()) {
+        let c = mat::CauchyMatrix::<Fr>::random(n, &mut r.clone()).unwrap();
         let m = random_cauchy::<Fr>(n, &mut r).unwrap();
         let s = security_level(&m, 25);
+        assert_eq!(security_level(&mat::Matrix::new(&m).unwrap(), 25), s);
+        // The same source of randomness yields the same matrix without the explicit entries
+        assert_eq!(c.entries(), m);
+        assert_eq!(security_level(&c, 25), s);
         match i {
             // The expected output has been computed by means of a SageMath script, which uses
             // the built-in tools for working with matrices and polynomials over finite fields.
@@ -28,4 +252,33 @@ fn test_security_level() {
             _ => assert_eq!(s, None),
         }
     }
+    // The polynomial products are computed without the FFT, whose domains of the sizes
+    // greater than 4 do not exist in F13, so the matrices over F13 are checked properly
+    for (_, n) in (0..60).zip((2..=6).cycle()) {
+        let m = random_cauchy::<F13>(n, &mut r).unwrap();
+        assert_eq!(security_level(&m, 20), reference::security_level(&m, 20));
+    }
+}
+
+#[test]
+fn test_tracked_security_level() {
+    let mut r = ChaCha8Rng::seed_from_u64(789);
+    let mut t = Statistics::default();
+    for (_, n) in (0..30).zip((2..=7).cycle()) {
+        let m = random_cauchy::<Fr>(n, &mut r).unwrap();
+        assert_eq!(
+            tracked_security_level(&m, 25, &mut t),
+            security_level(&m, 25)
+        );
+    }
+    assert_eq!(
+        t,
+        Statistics {
+            krylov: 0,
+            stickelberger: 18,
+            reducible: 5,
+            subfield: 0,
+            secure: 7
+        }
+    );
 }
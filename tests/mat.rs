@@ -1,5 +1,430 @@
 use ark_bn254::Fr;
-use mdsecheck::mat::{product_matrix, product_vector, system_solution};
+use ark_ff::{Field, UniformRand};
+use mdsecheck::{
+    cauchy,
+    mat::{
+        characteristic_polynomial, eigenpairs, kernel, polynomial_value, power_matrix,
+        product_matrix, product_vector, system_solution, CauchyMatrix, LinearMap, Matrix,
+    },
+    poly::new,
+    random_cauchy,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[test]
+fn test_cauchy_matrix() {
+    let (x, y) = ([Fr::from(1), Fr::from(2)], [Fr::from(3), Fr::from(5)]);
+    assert_eq!(CauchyMatrix::<Fr>::new(&[], &[]), None);
+    assert_eq!(CauchyMatrix::new(&x, &y[..1]), None);
+    assert_eq!(CauchyMatrix::new(&x, &[Fr::from(3), Fr::from(1)]), None);
+    let m = CauchyMatrix::new(&x, &y).unwrap();
+    assert_eq!((m.order(), m.x(), m.y()), (Some(2), &x[..], &y[..]));
+    assert_eq!(m.entry(1, 0), Some(-Fr::from(4).inverse().unwrap()));
+    assert_eq!(m.entry(2, 0), None);
+    assert_eq!(Some(m.entries()), cauchy(&x, &y));
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    assert_eq!(CauchyMatrix::<Fr>::random(0, &mut r), None);
+    // The random matrices are the same as the ones created by the function "random_cauchy",
+    // and the products are the same as the ones of their explicit entries, including the
+    // orders, for which the fraction sums and the remainder tree are used
+    for n in [1, 2, 5, 40, 255, 256, 300, 700] {
+        let (mut s, mut t) = (ChaCha8Rng::seed_from_u64(n), ChaCha8Rng::seed_from_u64(n));
+        let m = CauchyMatrix::<Fr>::random(n as u32, &mut s).unwrap();
+        let e = m.entries();
+        assert_eq!(Some(&e), random_cauchy::<Fr>(n as u32, &mut t).as_ref());
+        let v = (0..n).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>();
+        assert_eq!(m.product(&v), product_vector(&e, &v));
+        assert_eq!(m.product(&v[1..]), None);
+        // The data computed by the first product is reused, and it does not affect equality
+        let v = (0..n).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>();
+        assert_eq!(m.clone().product(&v), product_vector(&e, &v));
+        assert_eq!(m, CauchyMatrix::new(m.x(), m.y()).unwrap());
+    }
+}
+
+#[test]
+fn test_characteristic_polynomial() {
+    assert_eq!(characteristic_polynomial(&[] as &[&[Fr]]), None);
+    assert_eq!(characteristic_polynomial(&[vec![]] as &[Vec<Fr>]), None);
+    assert_eq!(
+        characteristic_polynomial(&[
+            [Fr::from(1), Fr::from(2), Fr::from(3)],
+            [Fr::from(4), Fr::from(5), Fr::from(6)]
+        ]),
+        None
+    );
+    assert_eq!(
+        characteristic_polynomial(&[vec![Fr::from(1), Fr::from(2)], vec![Fr::from(3)]]),
+        None
+    );
+    assert_eq!(
+        characteristic_polynomial(&[[Fr::from(7)]]),
+        Some(new(&[1, -7]))
+    );
+    assert_eq!(
+        characteristic_polynomial(&[[Fr::from(2), Fr::from(1)], [Fr::from(1), Fr::from(2)]]),
+        Some(new(&[1, -4, 3]))
+    );
+    assert_eq!(
+        characteristic_polynomial(&[
+            [Fr::from(1), Fr::from(2), Fr::from(3)],
+            [Fr::from(4), Fr::from(5), Fr::from(6)],
+            [Fr::from(7), Fr::from(8), Fr::from(10)]
+        ]),
+        Some(new(&[1, -16, -12, 3]))
+    );
+    assert_eq!(
+        characteristic_polynomial(&[
+            [Fr::from(0), Fr::from(0), Fr::from(1)],
+            [Fr::from(1), Fr::from(0), Fr::from(0)],
+            [Fr::from(0), Fr::from(1), Fr::from(0)]
+        ]),
+        Some(new(&[1, 0, 0, -1]))
+    );
+    assert_eq!(
+        characteristic_polynomial(&[
+            [Fr::from(1), Fr::from(0), Fr::from(0)],
+            [Fr::from(0), Fr::from(0), Fr::from(1)],
+            [Fr::from(0), Fr::from(1), Fr::from(0)]
+        ]),
+        Some(new(&[1, -1, -1, 1]))
+    );
+    assert_eq!(
+        characteristic_polynomial(&[
+            [Fr::from(2), Fr::from(0), Fr::from(1), Fr::from(0)],
+            [Fr::from(0), Fr::from(0), Fr::from(0), Fr::from(0)],
+            [Fr::from(0), Fr::from(0), Fr::from(0), Fr::from(3)],
+            [Fr::from(0), Fr::from(5), Fr::from(0), Fr::from(0)]
+        ]),
+        Some(new(&[1, -2, 0, 0, 0]))
+    );
+}
+
+#[test]
+fn test_eigenpairs() {
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    assert_eq!(eigenpairs(&[] as &[&[Fr]], &mut r), None);
+    assert_eq!(
+        eigenpairs(
+            &[
+                [Fr::from(1), Fr::from(2), Fr::from(3)],
+                [Fr::from(4), Fr::from(5), Fr::from(6)]
+            ],
+            &mut r
+        ),
+        None
+    );
+    assert_eq!(
+        eigenpairs(
+            &[[Fr::from(0), Fr::from(5)], [Fr::from(1), Fr::from(0)]],
+            &mut r
+        ),
+        Some(vec![])
+    );
+    assert_eq!(
+        eigenpairs(
+            &[[Fr::from(2), Fr::from(1)], [Fr::from(1), Fr::from(2)]],
+            &mut r
+        ),
+        Some(vec![
+            (Fr::from(1), vec![vec![-Fr::from(1), Fr::from(1)]]),
+            (Fr::from(3), vec![vec![Fr::from(1), Fr::from(1)]])
+        ])
+    );
+    assert_eq!(
+        eigenpairs(
+            &[[Fr::from(4), Fr::from(0)], [Fr::from(0), Fr::from(4)]],
+            &mut r
+        ),
+        Some(vec![(
+            Fr::from(4),
+            vec![
+                vec![Fr::from(1), Fr::from(0)],
+                vec![Fr::from(0), Fr::from(1)]
+            ]
+        )])
+    );
+    assert_eq!(
+        eigenpairs(
+            &[[Fr::from(4), Fr::from(1)], [Fr::from(0), Fr::from(4)]],
+            &mut r
+        ),
+        Some(vec![(Fr::from(4), vec![vec![Fr::from(1), Fr::from(0)]])])
+    );
+    for n in 2..=6 {
+        let m = random_cauchy::<Fr>(n, &mut r).unwrap();
+        for (e, b) in eigenpairs(&m, &mut r).unwrap() {
+            for v in b {
+                let u = product_vector(&m, &v).unwrap();
+                assert_eq!(u, v.iter().map(|x| *x * e).collect::<Vec<_>>());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_kernel() {
+    assert_eq!(kernel(&[] as &[&[Fr]]), None);
+    assert_eq!(kernel(&[vec![]] as &[Vec<Fr>]), None);
+    assert_eq!(
+        kernel(&[vec![Fr::from(1), Fr::from(2)], vec![Fr::from(3)]]),
+        None
+    );
+    assert_eq!(
+        kernel(&[[Fr::from(1), Fr::from(2)], [Fr::from(3), Fr::from(4)]]),
+        Some(vec![])
+    );
+    assert_eq!(
+        kernel(&[[Fr::from(0), Fr::from(0)], [Fr::from(0), Fr::from(0)]]),
+        Some(vec![
+            vec![Fr::from(1), Fr::from(0)],
+            vec![Fr::from(0), Fr::from(1)]
+        ])
+    );
+    assert_eq!(
+        kernel(&[
+            [Fr::from(1), Fr::from(2), Fr::from(3)],
+            [Fr::from(4), Fr::from(5), Fr::from(6)]
+        ]),
+        Some(vec![vec![Fr::from(1), -Fr::from(2), Fr::from(1)]])
+    );
+    assert_eq!(
+        kernel(&[
+            [Fr::from(1), Fr::from(2), Fr::from(3)],
+            [Fr::from(4), Fr::from(5), Fr::from(6)],
+            [Fr::from(7), Fr::from(8), Fr::from(9)]
+        ]),
+        Some(vec![vec![Fr::from(1), -Fr::from(2), Fr::from(1)]])
+    );
+    assert_eq!(
+        kernel(&[
+            [Fr::from(0), Fr::from(1), Fr::from(2), Fr::from(0)],
+            [Fr::from(0), Fr::from(2), Fr::from(4), Fr::from(1)]
+        ]),
+        Some(vec![
+            vec![Fr::from(1), Fr::from(0), Fr::from(0), Fr::from(0)],
+            vec![Fr::from(0), -Fr::from(2), Fr::from(1), Fr::from(0)]
+        ])
+    );
+    assert_eq!(
+        kernel(&[[Fr::from(1)], [Fr::from(2)], [Fr::from(3)]]),
+        Some(vec![])
+    );
+}
+
+#[test]
+fn test_linear_map() {
+    let a = [[Fr::from(1), Fr::from(2)], [Fr::from(3), Fr::from(4)]];
+    assert_eq!(LinearMap::order(&a), Some(2));
+    assert_eq!(LinearMap::order(&[[Fr::from(1); 3]; 2]), None);
+    assert_eq!(LinearMap::order(&[] as &[Vec<Fr>]), None);
+    assert_eq!(
+        LinearMap::product(&a, &[Fr::from(1), Fr::from(1)]),
+        Some(vec![Fr::from(3), Fr::from(7)])
+    );
+    assert_eq!(LinearMap::product(&a, &[Fr::from(1)]), None);
+    // The Krylov sequence consists of the consecutive Fibonacci numbers
+    let mut w = vec![];
+    [[Fr::from(1), Fr::from(1)], [Fr::from(1), Fr::from(0)]].krylov_vectors(
+        &[Fr::from(1), Fr::from(0)],
+        6,
+        &mut w,
+    );
+    assert_eq!(
+        w,
+        [1, 0, 1, 1, 2, 1, 3, 2, 5, 3, 8, 5].map(Fr::from).to_vec()
+    );
+    // The Krylov vectors are the same for the explicit entries and the black box
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    for n in [1, 3, 10, 300] {
+        let m = CauchyMatrix::<Fr>::random(n, &mut r).unwrap();
+        let e = Matrix::new(&m.entries()).unwrap();
+        let v = (0..n).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>();
+        for k in [0, 1, 4] {
+            let (mut u, mut w) = (vec![Fr::from(7)], vec![Fr::from(7)]);
+            m.krylov_vectors(&v, k, &mut u);
+            e.krylov_vectors(&v, k, &mut w);
+            assert_eq!(u, w);
+            assert_eq!(u[1..].len(), k * n as usize);
+            for (p, q) in u[1..]
+                .chunks(n as usize)
+                .zip(u[1..].chunks(n as usize).skip(1))
+            {
+                assert_eq!(product_vector(&e, p), Some(q.to_vec()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_matrix() {
+    let f = |v: &[i64]| v.iter().map(|e| Fr::from(*e)).collect::<Vec<_>>();
+    assert_eq!(Matrix::new(&[] as &[&[Fr]]), None);
+    assert_eq!(Matrix::new(&[vec![]] as &[Vec<Fr>]), None);
+    assert_eq!(Matrix::new(&[f(&[1, 2]), f(&[3])]), None);
+    assert_eq!(Matrix::<Fr>::from_entries(0, 2, vec![]), None);
+    assert_eq!(Matrix::<Fr>::from_entries(2, 0, vec![]), None);
+    assert_eq!(Matrix::from_entries(2, 2, f(&[1, 2, 3])), None);
+    assert_eq!(Matrix::<Fr>::identity(0), None);
+    let a = Matrix::new(&[f(&[1, 2, 3]), f(&[4, 5, 6])]).unwrap();
+    assert_eq!(
+        Some(a.clone()),
+        Matrix::from_entries(2, 3, f(&[1, 2, 3, 4, 5, 6]))
+    );
+    assert_eq!((a.height(), a.width()), (2, 3));
+    assert_eq!(a.entries(), f(&[1, 2, 3, 4, 5, 6]));
+    assert_eq!(a.to_rows(), vec![f(&[1, 2, 3]), f(&[4, 5, 6])]);
+    assert_eq!(&a[1], f(&[4, 5, 6]));
+    assert_eq!(a[(0, 2)], Fr::from(3));
+    let t = a.transpose();
+    assert_eq!(t.to_rows(), vec![f(&[1, 4]), f(&[2, 5]), f(&[3, 6])]);
+    let mut b = a.clone();
+    b[(1, 0)] = Fr::from(7);
+    b[0][1] = Fr::from(8);
+    assert_eq!(b.to_rows(), vec![f(&[1, 8, 3]), f(&[7, 5, 6])]);
+    assert_eq!((&a + &t), None);
+    assert_eq!((&a - &t), None);
+    assert_eq!(
+        (&a + &b).unwrap().to_rows(),
+        vec![f(&[2, 10, 6]), f(&[11, 10, 12])]
+    );
+    assert_eq!(
+        (&a - &b).unwrap().to_rows(),
+        vec![f(&[0, -6, 0]), f(&[-3, 0, 0])]
+    );
+    assert_eq!((-&a).to_rows(), vec![f(&[-1, -2, -3]), f(&[-4, -5, -6])]);
+    assert_eq!(
+        (&a * Fr::from(2)).to_rows(),
+        vec![f(&[2, 4, 6]), f(&[8, 10, 12])]
+    );
+    assert_eq!(&a * &a, None);
+    assert_eq!(
+        (&a * &t).unwrap().to_rows(),
+        vec![f(&[14, 32]), f(&[32, 77])]
+    );
+    assert_eq!(&a * &f(&[1, 1])[..], None);
+    assert_eq!(&a * &f(&[1, 0, -1])[..], Some(f(&[-2, -2])));
+    let i = Matrix::<Fr>::identity(3).unwrap();
+    assert_eq!(
+        i.to_rows(),
+        vec![f(&[1, 0, 0]), f(&[0, 1, 0]), f(&[0, 0, 1])]
+    );
+    assert_eq!((&a * &i), Some(a.clone()));
+    // The functions of the module give the same results for the flat and nested matrices
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    for n in 1..6 {
+        let c = random_cauchy::<Fr>(n, &mut r).unwrap();
+        let m = Matrix::new(&c).unwrap();
+        let v = (1..=n as i64).map(Fr::from).collect::<Vec<_>>();
+        assert_eq!(characteristic_polynomial(&m), characteristic_polynomial(&c));
+        assert_eq!(kernel(&m), kernel(&c));
+        assert_eq!(power_matrix(&m, &[5]), power_matrix(&c, &[5]));
+        assert_eq!(product_matrix(&m, &m), product_matrix(&c, &c));
+        assert_eq!((&m * &m).map(|p| p.to_rows()), product_matrix(&c, &c));
+        assert_eq!(product_vector(&m, &v), product_vector(&c, &v));
+        assert_eq!(system_solution(&m, &v), system_solution(&c, &v));
+    }
+}
+
+#[test]
+fn test_polynomial_value() {
+    assert_eq!(polynomial_value(&new(&[1, 1]), &[] as &[&[Fr]]), None);
+    assert_eq!(
+        polynomial_value(
+            &new(&[1, 1]),
+            &[
+                [Fr::from(1), Fr::from(2), Fr::from(3)],
+                [Fr::from(4), Fr::from(5), Fr::from(6)]
+            ]
+        ),
+        None
+    );
+    assert_eq!(
+        polynomial_value(
+            &new(&[0]),
+            &[[Fr::from(1), Fr::from(2)], [Fr::from(3), Fr::from(4)]]
+        ),
+        Some(vec![
+            vec![Fr::from(0), Fr::from(0)],
+            vec![Fr::from(0), Fr::from(0)]
+        ])
+    );
+    assert_eq!(
+        polynomial_value(
+            &new(&[5]),
+            &[[Fr::from(1), Fr::from(2)], [Fr::from(3), Fr::from(4)]]
+        ),
+        Some(vec![
+            vec![Fr::from(5), Fr::from(0)],
+            vec![Fr::from(0), Fr::from(5)]
+        ])
+    );
+    assert_eq!(
+        polynomial_value(
+            &new(&[1, 1]),
+            &[[Fr::from(1), Fr::from(2)], [Fr::from(3), Fr::from(4)]]
+        ),
+        Some(vec![
+            vec![Fr::from(2), Fr::from(2)],
+            vec![Fr::from(3), Fr::from(5)]
+        ])
+    );
+    assert_eq!(
+        polynomial_value(
+            &new(&[1, -4, 3]),
+            &[[Fr::from(2), Fr::from(1)], [Fr::from(1), Fr::from(2)]]
+        ),
+        Some(vec![
+            vec![Fr::from(0), Fr::from(0)],
+            vec![Fr::from(0), Fr::from(0)]
+        ])
+    );
+}
+
+#[test]
+fn test_power_matrix() {
+    assert_eq!(power_matrix(&[] as &[&[Fr]], &[2]), None);
+    assert_eq!(
+        power_matrix(
+            &[
+                [Fr::from(1), Fr::from(2), Fr::from(3)],
+                [Fr::from(4), Fr::from(5), Fr::from(6)]
+            ],
+            &[2]
+        ),
+        None
+    );
+    assert_eq!(
+        power_matrix(
+            &[[Fr::from(1), Fr::from(1)], [Fr::from(1), Fr::from(0)]],
+            &[0, 0]
+        ),
+        Some(vec![
+            vec![Fr::from(1), Fr::from(0)],
+            vec![Fr::from(0), Fr::from(1)]
+        ])
+    );
+    assert_eq!(
+        power_matrix(
+            &[[Fr::from(1), Fr::from(1)], [Fr::from(1), Fr::from(0)]],
+            &[10]
+        ),
+        Some(vec![
+            vec![Fr::from(89), Fr::from(55)],
+            vec![Fr::from(55), Fr::from(34)]
+        ])
+    );
+    assert_eq!(
+        power_matrix(
+            &[[Fr::from(2), Fr::from(0)], [Fr::from(0), Fr::from(1)]],
+            &[0, 1]
+        ),
+        Some(vec![
+            vec![Fr::from(2).pow([0, 1]), Fr::from(0)],
+            vec![Fr::from(0), Fr::from(1)]
+        ])
+    );
+}
 
 #[test]
 fn test_product_matrix() {
@@ -94,6 +519,25 @@ fn test_product_matrix() {
             vec![Fr::from(81), Fr::from(99)]
         ])
     );
+    // The products of the large matrices span several chunks and tiles
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    for (h, k, w) in [(1, 17, 1), (33, 16, 40), (70, 35, 65)] {
+        let a = (0..h)
+            .map(|_| (0..k).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let b = (0..k)
+            .map(|_| (0..w).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let c = a
+            .iter()
+            .map(|s| {
+                (0..w)
+                    .map(|x| (0..k).map(|i| s[i] * b[i][x]).sum())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(product_matrix(&a, &b), Some(c));
+    }
 }
 
 #[test]
@@ -153,6 +597,22 @@ fn test_product_vector() {
         ),
         Some(vec![Fr::from(13), Fr::from(29), Fr::from(22), Fr::from(50)])
     );
+    // The products of the long rows span several chunks
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    for k in [15, 16, 33, 100] {
+        let a = (0..3)
+            .map(|_| (0..k).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let v = (0..k).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>();
+        assert_eq!(
+            product_vector(&a, &v),
+            Some(
+                a.iter()
+                    .map(|s| s.iter().zip(v.iter()).map(|(x, y)| *x * y).sum())
+                    .collect()
+            )
+        );
+    }
 }
 
 #[test]
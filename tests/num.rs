@@ -1,4 +1,177 @@
-use mdsecheck::num::{prime_divisors, reversed_bits};
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use mdsecheck::num::{
+    big_factorization, cyclotomic_values, divisors, extension_group_factorization, factorization,
+    gcd, moebius, primality, prime_divisors, residue, reversed_bits, totient,
+};
+use num_bigint::BigUint;
+
+#[test]
+fn test_big_factorization() {
+    let b = |n: u128| BigUint::from(n);
+    assert_eq!(big_factorization(&b(0), 1000), None);
+    assert_eq!(big_factorization(&b(1), 1000), Some(vec![]));
+    assert_eq!(big_factorization(&b(2), 1000), Some(vec![(b(2), 1)]));
+    assert_eq!(
+        big_factorization(&b(7500), 1000),
+        Some(vec![(b(2), 2), (b(3), 1), (b(5), 4)])
+    );
+    assert_eq!(
+        big_factorization(&b((1 << 64) + 1), 100000),
+        Some(vec![(b(274177), 1), (b(67280421310721), 1)])
+    );
+    let (p, q) = (b((1 << 61) - 1), b((1 << 31) - 1));
+    assert_eq!(
+        big_factorization(&(&p * &q * &q), 100000),
+        Some(vec![(q.clone(), 2), (p.clone(), 1)])
+    );
+    // The iteration bound is too small for finding the divisor 1000003
+    assert_eq!(big_factorization(&(b(1000003) * &p), 10), None);
+}
+
+#[test]
+fn test_cyclotomic_values() {
+    let b = |n: u128| BigUint::from(n);
+    assert_eq!(cyclotomic_values(&b(1), 4), None);
+    assert_eq!(cyclotomic_values(&b(13), 0), None);
+    assert_eq!(cyclotomic_values(&b(13), 1), Some(vec![(1, b(12))]));
+    assert_eq!(
+        cyclotomic_values(&b(13), 6),
+        Some(vec![(1, b(12)), (2, b(14)), (3, b(183)), (6, b(157))])
+    );
+    assert_eq!(
+        cyclotomic_values(&b(2), 12),
+        Some(vec![
+            (1, b(1)),
+            (2, b(3)),
+            (3, b(7)),
+            (4, b(5)),
+            (6, b(3)),
+            (12, b(13))
+        ])
+    );
+}
+
+#[test]
+fn test_divisors() {
+    assert_eq!(divisors(0), vec![]);
+    assert_eq!(divisors(1), vec![1]);
+    assert_eq!(divisors(13), vec![1, 13]);
+    assert_eq!(divisors(12), vec![1, 2, 3, 4, 6, 12]);
+    assert_eq!(divisors(243), vec![1, 3, 9, 27, 81, 243]);
+    assert_eq!(divisors(323), vec![1, 17, 19, 323]);
+    for n in 1..200 {
+        assert_eq!(
+            divisors(n),
+            (1..=n).filter(|d| n % d == 0).collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_extension_group_factorization() {
+    let b = |n: u128| BigUint::from(n);
+    assert_eq!(extension_group_factorization(&b(0), 3, 1000), None);
+    assert_eq!(extension_group_factorization(&b(13), 0, 1000), None);
+    assert_eq!(
+        extension_group_factorization(&b(13), 6, 1000),
+        Some(vec![
+            (b(2), 3),
+            (b(3), 2),
+            (b(7), 1),
+            (b(61), 1),
+            (b(157), 1)
+        ])
+    );
+    for (p, k) in [(b(13), 6), (b(31), 6), (b((1 << 61) - 1), 2)] {
+        for n in 1..=k {
+            let f = extension_group_factorization(&p, n, 1000000).unwrap();
+            assert!(f.iter().all(|(q, _)| primality(q)));
+            assert_eq!(
+                f.iter().map(|(q, e)| q.pow(*e)).product::<BigUint>(),
+                p.pow(n) - 1u32
+            );
+        }
+    }
+}
+
+#[test]
+fn test_factorization() {
+    assert_eq!(factorization(0), vec![]);
+    assert_eq!(factorization(1), vec![]);
+    assert_eq!(factorization(2), vec![(2, 1)]);
+    assert_eq!(factorization(512), vec![(2, 9)]);
+    assert_eq!(factorization(7500), vec![(2, 2), (3, 1), (5, 4)]);
+    assert_eq!(
+        factorization(5898330),
+        vec![(2, 1), (3, 2), (5, 1), (65537, 1)]
+    );
+    assert_eq!(
+        factorization(u64::MAX),
+        vec![
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (6700417, 1)
+        ]
+    );
+    assert_eq!(factorization(1000000007), vec![(1000000007, 1)]);
+    assert_eq!(
+        factorization(65521 * 65519 * 65519),
+        vec![(65519, 2), (65521, 1)]
+    );
+}
+
+#[test]
+fn test_gcd() {
+    assert_eq!(gcd(0, 0), 0);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(gcd(7, 0), 7);
+    assert_eq!(gcd(1, 12), 1);
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(35, 64), 1);
+    assert_eq!(gcd(u64::MAX, u64::MAX - 1), 1);
+    assert_eq!(gcd(1 << 40, 3 << 20), 1 << 20);
+}
+
+#[test]
+fn test_moebius() {
+    assert_eq!(moebius(0), None);
+    assert_eq!(moebius(1), Some(1));
+    assert_eq!(moebius(2), Some(-1));
+    assert_eq!(moebius(4), Some(0));
+    assert_eq!(moebius(6), Some(1));
+    assert_eq!(moebius(30), Some(-1));
+    assert_eq!(moebius(60), Some(0));
+    // The sum of the Moebius function values over the divisors is 0 for n > 1
+    for n in 2..200 {
+        assert_eq!(
+            divisors(n)
+                .into_iter()
+                .map(|d| moebius(d).unwrap() as i64)
+                .sum::<i64>(),
+            0
+        );
+    }
+}
+
+#[test]
+fn test_primality() {
+    let b = |n: u128| BigUint::from(n);
+    for n in [0, 1, 4, 9, 561, 1105, 3215031751, (1 << 64) + 1, 1 << 100] {
+        assert!(!primality(&b(n)));
+    }
+    for n in [2, 3, 37, 41, 1000003, (1 << 61) - 1, (1 << 127) - 1] {
+        assert!(primality(&b(n)));
+    }
+    let r: BigUint = Fr::MODULUS.into();
+    assert!(primality(&r));
+    assert!(!primality(&(&r * &r)));
+    assert!(!primality(&(&r + 2u32)));
+}
 
 #[test]
 fn test_prime_divisors() {
@@ -18,6 +191,19 @@ fn test_prime_divisors() {
     assert_eq!(prime_divisors(5898330), vec![2, 3, 5, 65537]);
 }
 
+#[test]
+fn test_residue() {
+    assert_eq!(residue(&[], 0), None);
+    assert_eq!(residue(&[5], 0), None);
+    assert_eq!(residue(&[], 7), Some(0));
+    assert_eq!(residue(&[123], 1), Some(0));
+    assert_eq!(residue(&[123], 10), Some(3));
+    assert_eq!(residue(&[0, 1], 3), Some(1));
+    assert_eq!(residue(&[u64::MAX, u64::MAX], u64::MAX), Some(0));
+    assert_eq!(residue(&[7, 0, 1], 1 << 32), Some(7));
+    assert_eq!(residue(&[7, 0, 1], 1000000007), Some(279632284));
+}
+
 #[test]
 fn test_reversed_bits() {
     assert_eq!(reversed_bits(&[]).next(), None);
@@ -51,3 +237,16 @@ fn test_reversed_bits() {
         [&[true; 4][..], &[false; 60], &[true; 3], &[false]].concat()
     );
 }
+
+#[test]
+fn test_totient() {
+    assert_eq!(totient(0), 0);
+    assert_eq!(totient(1), 1);
+    assert_eq!(totient(13), 12);
+    assert_eq!(totient(12), 4);
+    assert_eq!(totient(7500), 2000);
+    // The sum of the totient function values over the divisors is the number itself
+    for n in 1..200 {
+        assert_eq!(divisors(n).into_iter().map(totient).sum::<u64>(), n);
+    }
+}
@@ -1,6 +1,20 @@
+#![allow(clippy::bool_assert_comparison)]
+
 use ark_bn254::Fr;
-use ark_poly::{polynomial::univariate::DensePolynomial, DenseUVPolynomial};
-use mdsecheck::poly::{coprimality, new, power_modulo, reduced_modulo};
+use ark_ff::{Field, Fp64, MontBackend, MontConfig, UniformRand, Zero};
+use ark_poly::{polynomial::univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+use mdsecheck::poly::{
+    coprimality, derivative, discriminant, distinct_degree_factors, extended_gcd, factors,
+    frobenius_image, frobenius_matrix, gcd, irreducibility, new, power_modulo, power_polynomial,
+    power_sums, product, quotient, reduced_modulo, resultant, roots, stickelberger_admissibility,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[derive(MontConfig)]
+#[modulus = "13"]
+#[generator = "2"]
+pub struct F13Config;
+pub type F13 = Fp64<MontBackend<F13Config, 1>>;
 
 #[test]
 fn test_coprimality() {
@@ -16,6 +30,230 @@ fn test_coprimality() {
     assert_eq!(coprimality(&new::<Fr>(&[1, 5, 6]), &new(&[1, 6, 9])), false);
 }
 
+#[test]
+fn test_derivative() {
+    assert_eq!(derivative(&new::<Fr>(&[0])), new(&[0]));
+    assert_eq!(derivative(&new::<Fr>(&[7])), new(&[0]));
+    assert_eq!(derivative(&new::<Fr>(&[3, 1])), new(&[3]));
+    assert_eq!(derivative(&new::<Fr>(&[1, 2, 3, 4])), new(&[3, 4, 3]));
+}
+
+#[test]
+fn test_discriminant() {
+    assert_eq!(discriminant(&new::<Fr>(&[0])), None);
+    assert_eq!(discriminant(&new::<Fr>(&[7])), None);
+    assert_eq!(discriminant(&new::<Fr>(&[3, 5])), Some(Fr::from(1)));
+    assert_eq!(discriminant(&new::<Fr>(&[1, 0, -5])), Some(Fr::from(20)));
+    assert_eq!(discriminant(&new::<Fr>(&[1, -2, 1])), Some(Fr::from(0)));
+    assert_eq!(discriminant(&new::<Fr>(&[2, 3, 1])), Some(Fr::from(1)));
+    assert_eq!(
+        discriminant(&new::<Fr>(&[1, 0, -1, 1])),
+        Some(-Fr::from(23))
+    );
+    assert_eq!(
+        discriminant(&new::<Fr>(&[1, -10, 35, -50, 24])),
+        Some(Fr::from(144))
+    );
+}
+
+#[test]
+fn test_distinct_degree_factors() {
+    assert_eq!(distinct_degree_factors(&new::<Fr>(&[0])), None);
+    assert_eq!(distinct_degree_factors(&new::<Fr>(&[3])), Some(vec![]));
+    assert_eq!(
+        distinct_degree_factors(&new::<Fr>(&[2, -4])),
+        Some(vec![(new(&[1, -2]), 1)])
+    );
+    assert_eq!(
+        distinct_degree_factors(&new::<Fr>(&[1, 0, 0, -5])),
+        Some(vec![(new(&[1, 0, 0, -5]), 3)])
+    );
+    let c = [
+        new::<Fr>(&[1, -1]),
+        new(&[1, -2]),
+        new(&[1, 0, -5]),
+        new(&[1, 0, 0, -5]),
+    ]
+    .iter()
+    .fold(new(&[1]), |p, e| &p * e);
+    assert_eq!(
+        distinct_degree_factors(&c),
+        Some(vec![
+            (new(&[1, -3, 2]), 1),
+            (new(&[1, 0, -5]), 2),
+            (new(&[1, 0, 0, -5]), 3)
+        ])
+    );
+}
+
+#[test]
+fn test_extended_gcd() {
+    let (z, o) = (new::<Fr>(&[0]), new::<Fr>(&[1]));
+    assert_eq!(extended_gcd(&z, &z), (z.clone(), o.clone(), z.clone()));
+    assert_eq!(
+        extended_gcd(&new(&[2, 2]), &z),
+        (
+            new(&[1, 1]),
+            new(&[Fr::from(2).inverse().unwrap()]),
+            z.clone()
+        )
+    );
+    assert_eq!(
+        extended_gcd(&z, &new(&[3])),
+        (o.clone(), z.clone(), new(&[Fr::from(3).inverse().unwrap()]))
+    );
+    assert_eq!(
+        extended_gcd(&new(&[1, 0, 0]), &new(&[1, 1])),
+        (o.clone(), o.clone(), new(&[-1, 1]))
+    );
+    // Checking the Bezout identity and the divisibility for the polynomials of the
+    // degrees, which are large enough for the half-GCD method to be used
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    for (i, (n, k)) in [(40, 35), (80, 79), (100, 20), (90, 90), (70, 0), (130, 64)]
+        .into_iter()
+        .enumerate()
+    {
+        let c = DensePolynomial::<Fr>::rand(i * 5, &mut r);
+        let a = product(&DensePolynomial::rand(n, &mut r), &c);
+        let b = product(&DensePolynomial::rand(k, &mut r), &c);
+        let (g, s, t) = extended_gcd(&a, &b);
+        assert_eq!(&product(&s, &a) + &product(&t, &b), g);
+        assert_eq!(g.degree(), c.degree());
+        assert!(reduced_modulo(&a, &g).unwrap().is_zero());
+        assert!(reduced_modulo(&b, &g).unwrap().is_zero());
+        assert_eq!(g.coeffs[g.degree()], Fr::from(1));
+    }
+}
+
+#[test]
+fn test_factors() {
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    assert_eq!(factors(&new::<Fr>(&[0]), &mut r), None);
+    assert_eq!(factors(&new::<Fr>(&[3]), &mut r), Some(vec![]));
+    assert_eq!(
+        factors(&new::<Fr>(&[2, 0, 0, -10, 0, 0]), &mut r),
+        Some(vec![(new(&[1, 0]), 2), (new(&[1, 0, 0, -5]), 1)])
+    );
+    let c = [
+        new::<Fr>(&[1, -1]),
+        new(&[1, 0, -5]),
+        new(&[1, 2]),
+        new(&[1, 0, -5]),
+        new(&[1, -1]),
+        new(&[1, 0, -5]),
+    ]
+    .iter()
+    .fold(new(&[1]), |p, e| &p * e);
+    assert_eq!(
+        factors(&c, &mut r),
+        Some(vec![
+            (new(&[1, 2]), 1),
+            (new(&[1, -1]), 2),
+            (new(&[1, 0, -5]), 3)
+        ])
+    );
+    let c = [
+        new::<Fr>(&[1, 0, 0, -5]),
+        new(&[1, 0, 0, -8]),
+        new(&[1, 0, 0, -10]),
+    ]
+    .iter()
+    .fold(new(&[1]), |p, e| &p * e);
+    let f = factors(&c, &mut r).unwrap();
+    assert_eq!(f.iter().map(|(_, m)| m).sum::<u32>() as usize, f.len());
+    assert_eq!(f.iter().map(|(g, _)| g.degree()).sum::<usize>(), 9);
+    assert!(f.iter().all(|(g, _)| irreducibility(g)));
+    assert_eq!(f.iter().fold(new(&[1]), |p, (g, _)| &p * g), c);
+}
+
+#[test]
+fn test_frobenius_image() {
+    let c = new::<Fr>(&[1, 2, 3, 4]);
+    let m = frobenius_matrix(&c).unwrap();
+    assert_eq!(frobenius_image(&new(&[1, 0, 0, 0]), &m), None);
+    assert_eq!(frobenius_image(&new(&[1, 0]), &m[..2]), None);
+    assert_eq!(frobenius_image(&new(&[0]), &m), Some(new(&[0])));
+    assert_eq!(frobenius_image(&new(&[5]), &m), Some(new(&[5])));
+    assert_eq!(
+        frobenius_image(&new(&[5]), &mdsecheck::mat::Matrix::new(&m).unwrap()),
+        Some(new(&[5]))
+    );
+    // Comparing with the powers computed by means of the binary exponentiation
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    for n in 1..=6 {
+        let c = DensePolynomial::<Fr>::rand(n, &mut r);
+        let m = frobenius_matrix(&c).unwrap();
+        let mut g = DensePolynomial::rand(n - 1, &mut r);
+        for _ in 0..3 {
+            let h = frobenius_image(&g, &m).unwrap();
+            assert_eq!(h, power_modulo(&g, Fr::characteristic(), &c).unwrap());
+            g = h;
+        }
+    }
+}
+
+#[test]
+fn test_frobenius_matrix() {
+    assert_eq!(frobenius_matrix(&new::<Fr>(&[0])), None);
+    assert_eq!(frobenius_matrix(&new::<Fr>(&[3])), None);
+    // The Frobenius endomorphism is the identity for the linear moduli, and
+    // for the modulus x^2 - 5, x^p = 5^((p - 1) / 2) * x = -x, since 5 is not
+    // a quadratic residue in the BN254 scalar field
+    assert_eq!(
+        frobenius_matrix(&new::<Fr>(&[2, 3])),
+        Some(vec![vec![Fr::from(1)]])
+    );
+    assert_eq!(
+        frobenius_matrix(&new::<Fr>(&[1, 0, -5])),
+        Some(vec![
+            vec![Fr::from(1), Fr::from(0)],
+            vec![Fr::from(0), -Fr::from(1)]
+        ])
+    );
+}
+
+#[test]
+fn test_gcd() {
+    assert_eq!(gcd(&new::<Fr>(&[0]), &new(&[0])), new(&[0]));
+    assert_eq!(gcd(&new::<Fr>(&[2, 2]), &new(&[0])), new(&[1, 1]));
+    assert_eq!(gcd(&new::<Fr>(&[0]), &new(&[3])), new(&[1]));
+    assert_eq!(
+        gcd(&new::<Fr>(&[1, 0, -1]), &new(&[1, -3, 2])),
+        new(&[1, -1])
+    );
+    assert_eq!(gcd(&new::<Fr>(&[1, 0, -5]), &new(&[1, -2])), new(&[1]));
+    assert_eq!(
+        gcd(&new::<Fr>(&[2, -10, 12]), &new(&[3, -15, 18])),
+        new(&[1, -5, 6])
+    );
+    // Comparing with the classical Euclidean method for the degrees,
+    // which are large enough for the half-GCD method to be used
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    for (n, k, d) in [(50, 40, 0), (60, 59, 10), (120, 100, 33), (64, 64, 64)] {
+        let c = DensePolynomial::<Fr>::rand(d, &mut r);
+        let mut a = product(&DensePolynomial::rand(n, &mut r), &c);
+        let mut b = product(&DensePolynomial::rand(k, &mut r), &c);
+        let g = gcd(&a, &b);
+        while let Some(e) = reduced_modulo(&a, &b) {
+            (a, b) = (b, e);
+        }
+        assert_eq!(g, &a * a.coeffs[a.degree()].inverse().unwrap());
+    }
+}
+
+#[test]
+fn test_irreducibility() {
+    assert_eq!(irreducibility(&new::<Fr>(&[0])), false);
+    assert_eq!(irreducibility(&new::<Fr>(&[7])), false);
+    assert_eq!(irreducibility(&new::<Fr>(&[3, 1])), true);
+    assert_eq!(irreducibility(&new::<Fr>(&[1, 0, -4])), false);
+    assert_eq!(irreducibility(&new::<Fr>(&[1, 0, -5])), true);
+    assert_eq!(irreducibility(&new::<Fr>(&[2, 0, -10])), true);
+    assert_eq!(irreducibility(&new::<Fr>(&[1, 0, 0, -5])), true);
+    assert_eq!(irreducibility(&new::<Fr>(&[1, 0, 0, -125])), false);
+    assert_eq!(irreducibility(&new::<Fr>(&[1, 0, -10, 0, 25])), false);
+}
+
 #[test]
 fn test_new() {
     assert_eq!(
@@ -80,6 +318,141 @@ fn test_power_modulo() {
     );
 }
 
+#[test]
+fn test_power_polynomial() {
+    assert_eq!(power_polynomial(&new::<Fr>(&[0]), 2), None);
+    assert_eq!(power_polynomial(&new::<Fr>(&[5]), 2), None);
+    assert_eq!(
+        power_polynomial(&new::<Fr>(&[1, -3]), 0),
+        Some(new(&[1, -1]))
+    );
+    assert_eq!(
+        power_polynomial(&new::<Fr>(&[1, -3]), 4),
+        Some(new(&[1, -81]))
+    );
+    assert_eq!(
+        power_polynomial(&new::<Fr>(&[1, 0, -5]), 1),
+        Some(new(&[1, 0, -5]))
+    );
+    assert_eq!(
+        power_polynomial(&new::<Fr>(&[1, 0, -5]), 2),
+        Some(new(&[1, -10, 25]))
+    );
+    assert_eq!(
+        power_polynomial(&new::<Fr>(&[2, 0, -10]), 3),
+        Some(new(&[1, 0, -125]))
+    );
+    assert_eq!(
+        power_polynomial(&new::<Fr>(&[1, -5, 6]), 3),
+        Some(new(&[1, -35, 216]))
+    );
+    assert_eq!(
+        power_polynomial(&new::<Fr>(&[1, 0, 1]), 2),
+        Some(new(&[1, 2, 1]))
+    );
+    assert_eq!(
+        power_polynomial(&new::<Fr>(&[1, 0, 0, -5]), 2),
+        Some(new(&[1, 0, 0, -25]))
+    );
+    assert_eq!(
+        power_polynomial(&new::<Fr>(&[1, 0, 0, -5]), 3),
+        Some(new(&[1, -15, 75, -125]))
+    );
+}
+
+#[test]
+fn test_power_sums() {
+    assert_eq!(power_sums(&new::<Fr>(&[0]), 3), None);
+    assert_eq!(power_sums(&new::<Fr>(&[4]), 3), None);
+    assert_eq!(power_sums(&new::<Fr>(&[1, -2]), 0), Some(vec![]));
+    assert_eq!(
+        power_sums(&new::<Fr>(&[1, -2]), 4),
+        Some(vec![Fr::from(1), Fr::from(2), Fr::from(4), Fr::from(8)])
+    );
+    assert_eq!(
+        power_sums(&new::<Fr>(&[1, 0, -5]), 5),
+        Some(vec![
+            Fr::from(2),
+            Fr::from(0),
+            Fr::from(10),
+            Fr::from(0),
+            Fr::from(50)
+        ])
+    );
+    assert_eq!(
+        power_sums(&new::<Fr>(&[2, -10, 12]), 4),
+        Some(vec![Fr::from(2), Fr::from(5), Fr::from(13), Fr::from(35)])
+    );
+}
+
+#[test]
+fn test_product() {
+    assert_eq!(product(&new::<Fr>(&[0]), &new(&[1, 2])), new(&[0]));
+    assert_eq!(product(&new::<Fr>(&[1, 2]), &new(&[0])), new(&[0]));
+    assert_eq!(product(&new::<Fr>(&[3]), &new(&[1, 2])), new(&[3, 6]));
+    assert_eq!(
+        product(&new::<Fr>(&[1, 1]), &new(&[1, -1])),
+        new(&[1, 0, -1])
+    );
+    assert_eq!(
+        product(&new::<Fr>(&[1, 2, 3]), &new(&[4, 0, 5, 6])),
+        new(&[4, 8, 17, 16, 27, 18])
+    );
+    // Comparing with the schoolbook method for the degrees, which are large enough for the
+    // Karatsuba method and the number theoretic transform, and the fields, which have and
+    // do not have a large enough multiplicative subgroup, whose order is a power of 2
+    fn schoolbook<F: Field>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> Vec<F> {
+        let mut c = vec![F::ZERO; a.coeffs.len() + b.coeffs.len() - 1];
+        for (i, x) in a.coeffs.iter().enumerate() {
+            for (j, y) in b.coeffs.iter().enumerate() {
+                c[i + j] += *x * y;
+            }
+        }
+        c
+    }
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    for (n, k) in [
+        (11, 40),
+        (12, 12),
+        (25, 24),
+        (40, 13),
+        (63, 64),
+        (100, 30),
+        (300, 257),
+    ] {
+        let (a, b) = (
+            DensePolynomial::<Fr>::rand(n, &mut r),
+            DensePolynomial::<Fr>::rand(k, &mut r),
+        );
+        assert_eq!(product(&a, &b).coeffs, schoolbook(&a, &b));
+        let (a, b) = (
+            DensePolynomial::<F13>::rand(n, &mut r),
+            DensePolynomial::<F13>::rand(k, &mut r),
+        );
+        assert_eq!(
+            product(&a, &b),
+            DensePolynomial::from_coefficients_vec(schoolbook(&a, &b))
+        );
+    }
+}
+
+#[test]
+fn test_quotient() {
+    assert_eq!(quotient(&new::<Fr>(&[1, 2, 3]), &new(&[0])), None);
+    assert_eq!(
+        quotient(&new::<Fr>(&[1, 1]), &new(&[1, 0, 0])),
+        Some(new(&[0]))
+    );
+    assert_eq!(
+        quotient(&new::<Fr>(&[1, 0, 0, -1]), &new(&[1, -1])),
+        Some(new(&[1, 1, 1]))
+    );
+    assert_eq!(
+        quotient(&new::<Fr>(&[3, 18, 39, 30, 21]), &new(&[1, 6, 12, 8])),
+        Some(new(&[3, 0]))
+    );
+}
+
 #[test]
 fn test_reduced_modulo() {
     assert_eq!(
@@ -99,3 +472,85 @@ fn test_reduced_modulo() {
         Some(new(&[3, 6, 21]))
     );
 }
+
+#[test]
+fn test_resultant() {
+    assert_eq!(resultant(&new::<Fr>(&[0]), &new(&[0])), Fr::from(0));
+    assert_eq!(resultant(&new::<Fr>(&[0]), &new(&[1, 2])), Fr::from(0));
+    assert_eq!(resultant(&new::<Fr>(&[3]), &new(&[4])), Fr::from(1));
+    assert_eq!(resultant(&new::<Fr>(&[3]), &new(&[1, 0, 1])), Fr::from(9));
+    assert_eq!(resultant(&new::<Fr>(&[1, 0, 1]), &new(&[3])), Fr::from(9));
+    assert_eq!(
+        resultant(&new::<Fr>(&[1, 0, -5]), &new(&[1, -2])),
+        -Fr::from(1)
+    );
+    assert_eq!(
+        resultant(&new::<Fr>(&[1, -2]), &new(&[1, 0, -5])),
+        -Fr::from(1)
+    );
+    assert_eq!(
+        resultant(&new::<Fr>(&[1, 0, -1]), &new(&[1, -1])),
+        Fr::from(0)
+    );
+    assert_eq!(
+        resultant(&new::<Fr>(&[1, 0, 1]), &new(&[1, 0, -1])),
+        Fr::from(4)
+    );
+    assert_eq!(
+        resultant(&new::<Fr>(&[2, -6, 4]), &new(&[1, -3, 0, 5])),
+        Fr::from(24)
+    );
+}
+
+#[test]
+fn test_roots() {
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    assert_eq!(roots(&new::<Fr>(&[0]), &mut r), None);
+    assert_eq!(roots(&new::<Fr>(&[5]), &mut r), Some(vec![]));
+    assert_eq!(roots(&new::<Fr>(&[2, -6]), &mut r), Some(vec![Fr::from(3)]));
+    assert_eq!(roots(&new::<Fr>(&[1, 0, -5]), &mut r), Some(vec![]));
+    assert_eq!(
+        roots(&new::<Fr>(&[1, -8, 16]), &mut r),
+        Some(vec![Fr::from(4)])
+    );
+    assert_eq!(
+        roots(&new::<Fr>(&[1, 0, 0, 0, 0, -1]), &mut r),
+        Some(vec![Fr::from(1)])
+    );
+    assert_eq!(
+        roots(&new::<Fr>(&[1, -6, 6, 24, -55, 30]), &mut r),
+        Some(vec![Fr::from(1), Fr::from(2), Fr::from(3)])
+    );
+    let mut v = (0..10).map(|_| Fr::rand(&mut r)).collect::<Vec<_>>();
+    let c = v
+        .iter()
+        .fold(new(&[1]), |p, e| &p * &new(&[Fr::from(1), -*e]));
+    v.sort();
+    assert_eq!(roots(&(&c * &new(&[1, 0, 0, -5])), &mut r), Some(v));
+}
+
+#[test]
+fn test_stickelberger_admissibility() {
+    assert_eq!(stickelberger_admissibility(&new::<Fr>(&[0])), false);
+    assert_eq!(stickelberger_admissibility(&new::<Fr>(&[7])), false);
+    assert_eq!(stickelberger_admissibility(&new::<Fr>(&[3, 1])), true);
+    assert_eq!(stickelberger_admissibility(&new::<Fr>(&[1, 0, -4])), false);
+    assert_eq!(stickelberger_admissibility(&new::<Fr>(&[1, 0, -5])), true);
+    assert_eq!(stickelberger_admissibility(&new::<Fr>(&[1, -2, 1])), false);
+    assert_eq!(
+        stickelberger_admissibility(&new::<Fr>(&[1, 0, 0, -5])),
+        true
+    );
+    assert_eq!(
+        stickelberger_admissibility(&new::<Fr>(&[1, -6, 11, -6])),
+        true
+    );
+    assert_eq!(
+        stickelberger_admissibility(&new::<Fr>(&[1, -1, -5, 5])),
+        false
+    );
+    assert_eq!(
+        stickelberger_admissibility(&new::<Fr>(&[1, 0, -10, 0, 25])),
+        false
+    );
+}
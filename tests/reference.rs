@@ -0,0 +1,90 @@
+use ark_bn254::Fr;
+use ark_ff::{Fp64, MontBackend, MontConfig};
+use ark_poly::{polynomial::univariate::DensePolynomial, DenseUVPolynomial};
+use mdsecheck::{mat, poly, random_cauchy, reference, security_level};
+use rand::Rng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[derive(MontConfig)]
+#[modulus = "13"]
+#[generator = "2"]
+pub struct F13Config;
+pub type F13 = Fp64<MontBackend<F13Config, 1>>;
+
+#[test]
+fn test_irreducibility() {
+    assert!(!reference::irreducibility::<Fr>(&poly::new(&[] as &[i64])));
+    assert!(!reference::irreducibility::<Fr>(&poly::new(&[5])));
+    assert!(reference::irreducibility::<Fr>(&poly::new(&[1, 3])));
+    assert!(reference::irreducibility::<Fr>(&poly::new(&[1, 0, 5])));
+    assert!(!reference::irreducibility::<Fr>(&poly::new(&[1, 0, -4])));
+    assert!(!reference::irreducibility::<Fr>(&poly::new(&[1, 2, 1])));
+    assert!(!reference::irreducibility::<Fr>(&poly::new(&[
+        1, 0, 5, 0, 25
+    ])));
+    // The polynomial x^13 - x - 1 over F13 is irreducible, and
+    // the polynomial x^13 - 1 has the zero derivative over F13
+    let mut c = vec![0; 14];
+    (c[0], c[12], c[13]) = (1, -1, -1);
+    assert!(reference::irreducibility::<F13>(&poly::new(&c)));
+    (c[12], c[13]) = (0, -1);
+    assert!(!reference::irreducibility::<F13>(&poly::new(&c)));
+    // Comparing with the Crandall-Pomerance irreducibility test
+    let mut r = ChaCha8Rng::seed_from_u64(111);
+    for n in 1..=5 {
+        for _ in 0..40 {
+            let mut c = (0..n)
+                .map(|_| F13::from(r.gen_range(0..13u64)))
+                .collect::<Vec<_>>();
+            c.push(F13::from(1));
+            let c = DensePolynomial::from_coefficients_vec(c);
+            assert_eq!(reference::irreducibility(&c), poly::irreducibility(&c));
+        }
+    }
+}
+
+#[test]
+fn test_minimal_polynomial() {
+    assert_eq!(reference::minimal_polynomial::<Fr>(&[] as &[&[Fr]]), None);
+    assert_eq!(
+        reference::minimal_polynomial(&[[Fr::from(1), Fr::from(2)], [Fr::from(3), Fr::from(4)]]),
+        Some(poly::new(&[1, -5, -2]))
+    );
+    assert_eq!(
+        reference::minimal_polynomial(&[[Fr::from(3), Fr::from(0)], [Fr::from(0), Fr::from(3)]]),
+        Some(poly::new(&[1, -3]))
+    );
+    assert_eq!(
+        reference::minimal_polynomial(&[[Fr::from(0), Fr::from(0)], [Fr::from(0), Fr::from(0)]]),
+        Some(poly::new(&[1, 0]))
+    );
+    // Comparing with the characteristic polynomials of the random Cauchy
+    // matrices, which coincide with the minimal polynomials in this case
+    let mut r = ChaCha8Rng::seed_from_u64(222);
+    for n in 1..=5 {
+        let m = random_cauchy::<Fr>(n, &mut r).unwrap();
+        assert_eq!(
+            reference::minimal_polynomial(&m),
+            mat::characteristic_polynomial(&m)
+        );
+    }
+}
+
+#[test]
+fn test_security_level() {
+    let m = [[Fr::from(0), Fr::from(5)], [Fr::from(1), Fr::from(0)]];
+    assert_eq!(reference::security_level(&m, 0), None);
+    assert_eq!(reference::security_level(&m[..1], 1), None);
+    assert_eq!(reference::security_level(&m, 1), Some(1));
+    assert_eq!(reference::security_level(&m, 2), Some(1));
+    // Cross-validating the MDSECheck method over the large and small fields
+    let mut r = ChaCha8Rng::seed_from_u64(333);
+    for (_, n) in (0..9).zip((2..=4).cycle()) {
+        let m = random_cauchy::<Fr>(n, &mut r).unwrap();
+        assert_eq!(reference::security_level(&m, 8), security_level(&m, 8));
+    }
+    for (_, n) in (0..30).zip((2..=4).cycle()) {
+        let m = random_cauchy::<F13>(n, &mut r).unwrap();
+        assert_eq!(reference::security_level(&m, 12), security_level(&m, 12));
+    }
+}
@@ -0,0 +1,90 @@
+use ark_bn254::Fr;
+use ark_ff::{Fp64, MontBackend, MontConfig};
+use ark_poly::{polynomial::univariate::DensePolynomial, DenseUVPolynomial};
+use mdsecheck::{
+    poly::{new, product, reduced_modulo},
+    ring::ModRing,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[derive(MontConfig)]
+#[modulus = "13"]
+#[generator = "2"]
+pub struct F13Config;
+pub type F13 = Fp64<MontBackend<F13Config, 1>>;
+
+#[test]
+fn test_new() {
+    assert!(ModRing::new(&new::<Fr>(&[0])).is_none());
+    assert!(ModRing::new(&new::<Fr>(&[5])).is_none());
+    let m = new::<Fr>(&[2, 0, 1]);
+    assert_eq!(ModRing::new(&m).unwrap().modulus(), &m);
+}
+
+#[test]
+fn test_power() {
+    let mut q = ModRing::new(&new::<Fr>(&[1, 0, 1])).unwrap();
+    // Since x^2 = -1 modulo x^2 + 1, x^4 = 1
+    assert_eq!(q.power(&new(&[1, 0]), &[0]), new(&[1]));
+    assert_eq!(q.power(&new(&[1, 0]), &[]), new(&[1]));
+    assert_eq!(q.power(&new(&[1, 0]), &[3]), new(&[-1, 0]));
+    assert_eq!(q.power(&new(&[1, 0]), &[1 << 40]), new(&[1]));
+    assert_eq!(q.power(&new(&[1, 0]), &[6, 1]), new(&[-1]));
+    assert_eq!(q.power(&new(&[0]), &[5]), new(&[0]));
+    // Comparing with the repeated multiplication for the exponents,
+    // which lead to the different sliding window widths
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    for n in 1..=4 {
+        let m = DensePolynomial::<F13>::rand(n, &mut r);
+        let mut q = ModRing::new(&m).unwrap();
+        let a = DensePolynomial::rand(n + 2, &mut r);
+        let mut p = new(&[1]);
+        for e in 0..300u64 {
+            if [0, 1, 2, 7, 100, 255, 299].contains(&e) || (e % 37 == 0) {
+                assert_eq!(q.power(&a, &[e]), p);
+            }
+            p = reduced_modulo(&product(&p, &a), &m).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_product() {
+    let mut q = ModRing::new(&new::<Fr>(&[1, 0, 1])).unwrap();
+    assert_eq!(q.product(&new(&[1, 0]), &new(&[1, 0])), new(&[-1]));
+    assert_eq!(q.product(&new(&[1, 2]), &new(&[0])), new(&[0]));
+    assert_eq!(q.product(&new(&[1, 1]), &new(&[1, -1])), new(&[-2]));
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    // The large degrees are included for the fast multiplication methods to be used
+    for n in (1..=8).chain([40, 150]) {
+        let m = DensePolynomial::<Fr>::rand(n, &mut r);
+        let mut q = ModRing::new(&m).unwrap();
+        for k in (0..2 * n).step_by(n.div_ceil(8)) {
+            let a = DensePolynomial::rand(k, &mut r);
+            let b = DensePolynomial::rand(2 * n - k, &mut r);
+            assert_eq!(
+                q.product(&a, &b),
+                reduced_modulo(&product(&a, &b), &m).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_reduced() {
+    let mut q = ModRing::new(&new::<Fr>(&[2, 0, 1])).unwrap();
+    assert_eq!(q.reduced(&new(&[0])), new(&[0]));
+    assert_eq!(q.reduced(&new(&[1, 3])), new(&[1, 3]));
+    assert_eq!(q.reduced(&new(&[2, 0, 1])), new(&[0]));
+    assert_eq!(q.reduced(&new(&[4, 0, 0, 0, 0])), new(&[1]));
+    let mut r = ChaCha8Rng::seed_from_u64(789);
+    for n in 1..=6 {
+        let m = DensePolynomial::<Fr>::rand(n, &mut r);
+        let mut q = ModRing::new(&m).unwrap();
+        // The reciprocal is extended on demand for the high degrees
+        for k in [0, n - 1, n, 2 * n - 2, 3 * n + 5, n, 10 * n] {
+            let a = DensePolynomial::rand(k, &mut r);
+            assert_eq!(q.reduced(&a), reduced_modulo(&a, &m).unwrap());
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use ark_ff::{Field, Fp64, MontBackend, MontConfig};
+use mdsecheck::{
+    mat::Matrix,
+    random_cauchy,
+    sim::{output_span, projective_differences, validation, Spn},
+    trail,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[derive(MontConfig)]
+#[modulus = "13"]
+#[generator = "2"]
+pub struct F13Config;
+pub type F13 = Fp64<MontBackend<F13Config, 1>>;
+
+#[derive(MontConfig)]
+#[modulus = "31"]
+#[generator = "3"]
+pub struct F31Config;
+pub type F31 = Fp64<MontBackend<F31Config, 1>>;
+
+fn f13(m: &[[u64; 3]; 3]) -> Vec<Vec<F13>> {
+    m.iter()
+        .map(|r| r.iter().map(|e| F13::from(*e)).collect())
+        .collect()
+}
+
+#[test]
+fn test_spn() {
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    let m = f13(&[[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+    assert_eq!(Spn::new(&m[..1], 1, 5, 4, &mut r), None);
+    assert_eq!(Spn::new(&m, 0, 5, 4, &mut r), None);
+    assert_eq!(Spn::new(&m, 4, 5, 4, &mut r), None);
+    assert_eq!(Spn::new(&m, 1, 0, 4, &mut r), None);
+    assert_eq!(Spn::new(&m, 1, 3, 4, &mut r), None);
+    assert_eq!(Spn::new(&m, 1, 6, 4, &mut r), None);
+    assert_eq!(Spn::new(&m, 1, 5, 4, &mut r).unwrap().rounds(), 4);
+    assert_eq!(Spn::new(&m, 3, 7, 0, &mut r).unwrap().rounds(), 0);
+    assert_eq!(
+        Spn::new(&Matrix::new(&m).unwrap(), 3, 7, 0, &mut r),
+        Spn::new(&m, 3, 7, 0, &mut r)
+    );
+    let m = random_cauchy::<F31>(2, &mut r).unwrap();
+    assert_eq!(Spn::new(&m, 1, 5, 4, &mut r), None);
+    let p = Spn::new(&m, 1, 7, 4, &mut r).unwrap();
+    assert_eq!(p.permutation(&[F31::from(1)]), None);
+    // Checking that the P-SPN is a permutation
+    let mut v = (0..31 * 31)
+        .map(|i| {
+            p.permutation(&[F31::from(i / 31), F31::from(i % 31)])
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    v.sort();
+    v.dedup();
+    assert_eq!(v.len(), 31 * 31);
+}
+
+#[test]
+fn test_output_span() {
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    let m = f13(&[[1, 1, 0], [1, 2, 0], [0, 0, 3]]);
+    let p = Spn::new(&m, 1, 5, 6, &mut r).unwrap();
+    let (z, o) = (F13::from(0), F13::from(1));
+    assert_eq!(output_span(&p, &[z, z, z], 10, &mut r), None);
+    assert_eq!(output_span(&p, &[z, o], 10, &mut r), None);
+    assert_eq!(output_span(&p, &[z, z, o], 0, &mut r), Some(vec![]));
+    // The last entry is not affected by the S-box, so
+    // the output difference is always the same
+    assert_eq!(
+        output_span(&p, &[z, z, o], 10, &mut r),
+        Some(vec![vec![z, z, F13::from(3).pow([6])]])
+    );
+    assert_eq!(output_span(&p, &[o, z, z], 10, &mut r).unwrap().len(), 2);
+    let m = random_cauchy::<F13>(3, &mut r).unwrap();
+    assert_eq!(trail::security_level(&m, 1, 6), Some(6));
+    let p = Spn::new(&m, 1, 5, 6, &mut r).unwrap();
+    assert_eq!(output_span(&p, &[o, z, z], 20, &mut r).unwrap().len(), 3);
+}
+
+#[test]
+fn test_projective_differences() {
+    let (z, o) = (F13::from(0), F13::from(1));
+    assert_eq!(projective_differences::<F13>(0, 100), None);
+    assert_eq!(projective_differences::<F13>(2, 13), None);
+    assert_eq!(projective_differences::<ark_bn254::Fr>(2, 100), None);
+    assert_eq!(projective_differences::<F13>(1, 1), Some(vec![vec![o]]));
+    let v = projective_differences::<F13>(2, 14).unwrap();
+    assert_eq!(v.len(), 14);
+    assert_eq!(v[0], vec![o, z]);
+    assert_eq!(v[1], vec![o, o]);
+    assert_eq!(v[12], vec![o, F13::from(12)]);
+    assert_eq!(v[13], vec![z, o]);
+    let v = projective_differences::<F13>(3, 1000).unwrap();
+    assert_eq!(v.len(), 183);
+    assert_eq!(v[14], vec![o, o, F13::from(1)]);
+    assert_eq!(v[182], vec![z, z, o]);
+}
+
+#[test]
+fn test_validation() {
+    let mut r = ChaCha8Rng::seed_from_u64(789);
+    let m = f13(&[[1, 1, 0], [1, 2, 0], [0, 0, 3]]);
+    assert_eq!(validation(&m, 1, 3, 8, 200, 20, &mut r), None);
+    assert_eq!(validation(&m, 1, 5, 8, 200, 2, &mut r), None);
+    let v = validation(&m, 1, 5, 8, 200, 20, &mut r).unwrap();
+    assert_eq!(v.level, None);
+    assert!(v.differential.unwrap().1.len() < 3);
+    // Checking that the probability-1 truncated differentials are found only for
+    // the matrices, for which the subspace trails of period 1 exist, so they are
+    // never found for the matrices accepted by the MDSECheck method
+    for (_, n) in (0..12).zip((2..=3).cycle()) {
+        let m = random_cauchy::<F13>(n, &mut r).unwrap();
+        let v = validation(&m, 1, 5, 8, 200, 4 * n as usize + 8, &mut r).unwrap();
+        assert_eq!(v.level, mdsecheck::security_level(&m, 8));
+        assert_eq!(
+            v.differential.is_some(),
+            trail::security_level(&m, 1, 8).is_none()
+        );
+        assert!(v.level.is_none() || v.differential.is_none());
+    }
+    // The spans, which are proper only due to the lack of samples, are not reported
+    let m = random_cauchy::<F13>(3, &mut r).unwrap();
+    assert_eq!(trail::security_level(&m, 1, 6), Some(6));
+    for k in 3..12 {
+        let v = validation(&m, 1, 5, 6, 200, k, &mut r).unwrap();
+        assert_eq!(v.differential, None);
+    }
+}
@@ -0,0 +1,83 @@
+use ark_bn254::Fr;
+use ark_ff::{Fp64, MontBackend, MontConfig};
+use mdsecheck::{
+    stats::{exhaustive, sampled, Distribution},
+    Statistics,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[derive(MontConfig)]
+#[modulus = "13"]
+#[generator = "2"]
+pub struct F13Config;
+pub type F13 = Fp64<MontBackend<F13Config, 1>>;
+
+#[derive(MontConfig)]
+#[modulus = "65537"]
+#[generator = "3"]
+pub struct F65537Config;
+pub type F65537 = Fp64<MontBackend<F65537Config, 1>>;
+
+#[derive(MontConfig)]
+#[modulus = "18446744069414584321"]
+#[generator = "7"]
+pub struct GoldilocksConfig;
+pub type Goldilocks = Fp64<MontBackend<GoldilocksConfig, 1>>;
+
+// Checks that the outcomes and the failure reasons are consistent
+fn check(d: &Distribution, l: usize) {
+    let s = &d.statistics;
+    assert_eq!(d.levels.len(), l + 1);
+    assert_eq!(d.levels[0], s.krylov + s.stickelberger + s.reducible);
+    assert_eq!(d.levels[1..l].iter().sum::<u64>(), s.subfield);
+    assert_eq!(d.levels[l], s.secure);
+}
+
+#[test]
+fn test_exhaustive() {
+    assert_eq!(exhaustive::<F13>(1, 4), None);
+    assert_eq!(exhaustive::<F13>(2, 0), None);
+    assert_eq!(exhaustive::<F13>(7, 4), None);
+    assert_eq!(exhaustive::<Fr>(2, 4), None);
+    // The fields, which fit in 64 bits, but have more than 2^16 elements, are rejected
+    assert_eq!(exhaustive::<F65537>(2, 4), None);
+    assert_eq!(exhaustive::<Goldilocks>(2, 4), None);
+    let d = exhaustive::<F13>(2, 6).unwrap();
+    check(&d, 6);
+    assert_eq!(d.total(), 12 * 11 * 10);
+    assert_eq!(
+        d,
+        Distribution {
+            levels: vec![552, 72, 0, 0, 0, 0, 696],
+            statistics: Statistics {
+                krylov: 96,
+                stickelberger: 456,
+                reducible: 0,
+                subfield: 72,
+                secure: 696
+            }
+        }
+    );
+}
+
+#[test]
+fn test_sampled() {
+    let mut r = ChaCha8Rng::seed_from_u64(123);
+    assert_eq!(sampled::<F13>(1, 4, 10, &mut r), None);
+    assert_eq!(sampled::<F13>(2, 0, 10, &mut r), None);
+    assert_eq!(sampled::<F13>(7, 4, 10, &mut r), None);
+    assert_eq!(
+        sampled::<F13>(2, 4, 0, &mut r),
+        Some(Distribution {
+            levels: vec![0; 5],
+            statistics: Statistics::default()
+        })
+    );
+    let d = sampled::<F13>(3, 6, 300, &mut r).unwrap();
+    check(&d, 6);
+    assert_eq!(d.total(), 300);
+    assert_eq!(d.levels, vec![212, 0, 1, 0, 0, 0, 87]);
+    let d = sampled::<Fr>(3, 6, 20, &mut r).unwrap();
+    check(&d, 6);
+    assert_eq!(d.levels, vec![15, 0, 0, 0, 0, 0, 5]);
+}
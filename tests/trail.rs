@@ -0,0 +1,213 @@
+use ark_bn254::Fr;
+use ark_ff::{Fp64, MontBackend, MontConfig};
+use mdsecheck::{
+    mat::Matrix,
+    random_cauchy,
+    trail::{active_trail, conditional_level, inactive_subspace, inactive_trail, security_level},
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+#[derive(MontConfig)]
+#[modulus = "13"]
+#[generator = "2"]
+pub struct F13Config;
+pub type F13 = Fp64<MontBackend<F13Config, 1>>;
+
+#[test]
+fn test_active_trail() {
+    let m = [
+        [Fr::from(1), Fr::from(1), Fr::from(0)],
+        [Fr::from(1), Fr::from(2), Fr::from(0)],
+        [Fr::from(0), Fr::from(0), Fr::from(3)],
+    ];
+    assert_eq!(active_trail(&[] as &[&[Fr]], 1), None);
+    assert_eq!(active_trail(&[[Fr::from(1)]], 1), None);
+    assert_eq!(active_trail(&m, 0), None);
+    assert_eq!(active_trail(&m, 4), None);
+    assert_eq!(
+        active_trail(&m, 1),
+        Some(vec![
+            vec![Fr::from(1), Fr::from(0), Fr::from(0)],
+            vec![Fr::from(1), Fr::from(1), Fr::from(0)]
+        ])
+    );
+    assert_eq!(
+        active_trail(&m, 3),
+        Some(vec![
+            vec![Fr::from(0), Fr::from(1), Fr::from(0)],
+            vec![Fr::from(1), Fr::from(2), Fr::from(0)]
+        ])
+    );
+    assert_eq!(
+        active_trail(&[[Fr::from(1), Fr::from(1)], [Fr::from(1), Fr::from(2)]], 1),
+        Some(vec![])
+    );
+    assert_eq!(
+        active_trail(&[[Fr::from(1), Fr::from(1)], [Fr::from(1), Fr::from(2)]], 2),
+        Some(vec![])
+    );
+    assert_eq!(
+        active_trail(&[[Fr::from(5), Fr::from(0)], [Fr::from(0), Fr::from(5)]], 1),
+        Some(vec![vec![Fr::from(1), Fr::from(0)]])
+    );
+}
+
+#[test]
+fn test_conditional_level() {
+    let m = [
+        [Fr::from(1), Fr::from(1), Fr::from(0)],
+        [Fr::from(1), Fr::from(2), Fr::from(0)],
+        [Fr::from(0), Fr::from(0), Fr::from(3)],
+    ];
+    assert_eq!(conditional_level(&m, &[], 5), None);
+    assert_eq!(conditional_level(&m, &[3], 5), None);
+    assert_eq!(conditional_level(&m, &[0], 5), None);
+    assert_eq!(conditional_level(&m, &[2], 5), None);
+    assert_eq!(conditional_level(&m, &[0, 2], 1), None);
+    assert_eq!(conditional_level(&m, &[0, 2], 5), Some(2));
+    assert_eq!(conditional_level(&m, &[0, 1, 2], 5), Some(1));
+    // For the matrices, which are accepted by the MDSECheck method, the subspaces
+    // avoiding a single S-box lose one dimension per round, since otherwise some
+    // of them would be invariant under the matrix
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    for (_, n) in (0..12).zip((2..=5).cycle()) {
+        let m = random_cauchy::<Fr>(n, &mut r).unwrap();
+        if mdsecheck::security_level(&m, 1).is_some() {
+            for p in 0..n as usize {
+                assert_eq!(conditional_level(&m, &[p], 10), Some(n));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_inactive_subspace() {
+    let m = [
+        [Fr::from(1), Fr::from(1), Fr::from(0)],
+        [Fr::from(1), Fr::from(2), Fr::from(0)],
+        [Fr::from(0), Fr::from(0), Fr::from(3)],
+    ];
+    assert_eq!(inactive_subspace(&[] as &[&[Fr]], &[0], 1), None);
+    assert_eq!(inactive_subspace(&[[Fr::from(1)]], &[0], 1), None);
+    assert_eq!(inactive_subspace(&m, &[], 1), None);
+    assert_eq!(inactive_subspace(&m, &[1, 3], 1), None);
+    assert_eq!(
+        inactive_subspace(&m, &[0], 0),
+        Some(vec![
+            vec![Fr::from(1), Fr::from(0), Fr::from(0)],
+            vec![Fr::from(0), Fr::from(1), Fr::from(0)],
+            vec![Fr::from(0), Fr::from(0), Fr::from(1)]
+        ])
+    );
+    assert_eq!(
+        inactive_subspace(&m, &[0], 1),
+        Some(vec![
+            vec![Fr::from(0), Fr::from(1), Fr::from(0)],
+            vec![Fr::from(0), Fr::from(0), Fr::from(1)]
+        ])
+    );
+    assert_eq!(
+        inactive_subspace(&m, &[0], 2),
+        Some(vec![vec![Fr::from(0), Fr::from(0), Fr::from(1)]])
+    );
+    assert_eq!(
+        inactive_subspace(&m, &[0], 10),
+        Some(vec![vec![Fr::from(0), Fr::from(0), Fr::from(1)]])
+    );
+    assert_eq!(
+        inactive_subspace(&m, &[1], 2),
+        Some(vec![vec![Fr::from(0), Fr::from(0), Fr::from(1)]])
+    );
+    assert_eq!(inactive_subspace(&m, &[0, 2], 2), Some(vec![]));
+}
+
+#[test]
+fn test_inactive_trail() {
+    let m = [
+        [Fr::from(1), Fr::from(1), Fr::from(0)],
+        [Fr::from(1), Fr::from(2), Fr::from(0)],
+        [Fr::from(0), Fr::from(0), Fr::from(3)],
+    ];
+    assert_eq!(inactive_trail(&[] as &[&[Fr]], 1), None);
+    assert_eq!(inactive_trail(&m, 0), None);
+    assert_eq!(inactive_trail(&m, 4), None);
+    assert_eq!(
+        inactive_trail(&m, 1),
+        Some(vec![vec![Fr::from(0), Fr::from(0), Fr::from(1)]])
+    );
+    assert_eq!(
+        inactive_trail(&Matrix::new(&m).unwrap(), 1),
+        inactive_trail(&m, 1)
+    );
+    assert_eq!(inactive_trail(&m, 3), Some(vec![]));
+    assert_eq!(
+        inactive_trail(&[[Fr::from(1), Fr::from(1)], [Fr::from(1), Fr::from(2)]], 1),
+        Some(vec![])
+    );
+    assert_eq!(
+        inactive_trail(&[[Fr::from(2), Fr::from(0)], [Fr::from(1), Fr::from(3)]], 1),
+        Some(vec![vec![Fr::from(0), Fr::from(1)]])
+    );
+}
+
+#[test]
+fn test_security_level() {
+    let m = [[Fr::from(0), Fr::from(5)], [Fr::from(1), Fr::from(0)]];
+    assert_eq!(security_level(&m, 1, 0), None);
+    assert_eq!(security_level(&m, 0, 3), None);
+    assert_eq!(security_level(&m, 1, 1), Some(1));
+    assert_eq!(security_level(&m, 1, 3), Some(1));
+    assert_eq!(mdsecheck::security_level(&m, 3), Some(1));
+    assert_eq!(
+        security_level(
+            &[[Fr::from(2), Fr::from(0)], [Fr::from(1), Fr::from(3)]],
+            1,
+            3
+        ),
+        None
+    );
+    // The inactive trail appears only for the square of the matrix
+    let m = [[5, 5, 9], [3, 8, 10], [9, 6, 1]].map(|r| r.map(F13::from));
+    let p = mdsecheck::mat::product_matrix(&m, &m).unwrap();
+    assert_eq!(inactive_trail(&m, 2), Some(vec![]));
+    assert_ne!(inactive_trail(&p, 2), Some(vec![]));
+    assert_eq!(active_trail(&p, 2), Some(vec![]));
+    assert_eq!(security_level(&m, 2, 10), Some(1));
+    // The matrices are the same as in the test of the function "security_level" of the
+    // crate, whose security levels have been computed by means of a SageMath script, and
+    // the levels of the secure matrices are the same for any number of the S-boxes
+    let mut r = ChaCha8Rng::seed_from_u64(456);
+    for (i, n) in (0..12).zip((2..=7).cycle()) {
+        let m = random_cauchy::<Fr>(n, &mut r).unwrap();
+        if matches!(i, 0 | 7 | 9) {
+            for s in 1..=n as usize {
+                assert_eq!(security_level(&m, s, 25), Some(25));
+            }
+        }
+    }
+    // If the characteristic polynomial of the matrix of order 2 is irreducible, then the
+    // level is the largest i, for which the i-th power of a root is not in the base field,
+    // so over F13 it is 1, 6 or 13, if the order of the root is divisible by 8 but not by 7,
+    // by 7 but not by 8, or by 56 respectively. The levels of all such companion matrices
+    // are the same for both methods
+    assert_eq!(
+        security_level(
+            &[[F13::from(0), F13::from(2)], [F13::from(1), F13::from(0)]],
+            1,
+            20
+        ),
+        Some(1)
+    );
+    let mut c = [0; 3];
+    for a in 1..13 {
+        for b in 0..13 {
+            let m = [[F13::from(0), F13::from(a)], [F13::from(1), F13::from(b)]];
+            if let Some(l) = mdsecheck::security_level(&m, 20) {
+                assert_eq!(security_level(&m, 1, 20), Some(l));
+                assert_eq!(security_level(&m, 2, 20), Some(l));
+                c[[1, 6, 13].iter().position(|e| *e == l).unwrap()] += 1;
+            }
+        }
+    }
+    assert_eq!(c, [6, 36, 36]);
+}